@@ -0,0 +1,172 @@
+//! Arithmetic on the proleptic Gregorian calendar and its ISO week numbering.
+//!
+//! Days are counted relative to 1970-01-01, which makes a fine anchor
+//! for converting between the three [`Date`][`crate::Date`] representations.
+
+// Leap years of the proleptic Gregorian calendar, year 0 is one of them.
+pub(crate) fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+pub(crate) fn days_in_year(year: i32) -> u32 {
+    if is_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+pub(crate) fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+// A year has 53 ISO weeks if it starts on a Thursday,
+// or if it is a leap year starting on a Wednesday.
+pub(crate) fn iso_weeks_in_year(year: i32) -> u32 {
+    // day of week of December 31st, 0 being Sunday, in `i64` to reach beyond an `i32` year
+    fn dec_31(year: i64) -> i64 {
+        (year + year.div_euclid(4) - year.div_euclid(100) + year.div_euclid(400)).rem_euclid(7)
+    }
+
+    let year = i64::from(year);
+    if dec_31(year) == 4 || dec_31(year - 1) == 3 {
+        53
+    } else {
+        52
+    }
+}
+
+// Days since 1970-01-01 of the given (valid) date, negative before it.
+// http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+pub(crate) fn days_from_ymd(year: i32, month: u32, day: u32) -> i64 {
+    days_from_civil(i64::from(year), month, day)
+}
+
+// `days_from_ymd` for years beyond an `i32`, like the ISO year after `i32::MAX`.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = year - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+// The inverse of `days_from_ymd`, `None` if the year doesn't fit an `i32`.
+pub(crate) fn ymd_from_days(days: i64) -> Option<(i32, u32, u32)> {
+    if !days_in_range(days) {
+        return None;
+    }
+    let (year, month, day) = civil_from_days(days);
+    Some((year as i32, month, day))
+}
+
+// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+// Whether the date `days` after 1970-01-01 has a year that fits an `i32`.
+fn days_in_range(days: i64) -> bool {
+    (days_from_ymd(i32::MIN, 1, 1)..=days_from_ymd(i32::MAX, 12, 31)).contains(&days)
+}
+
+pub(crate) fn days_from_ordinal(year: i32, ddd: u32) -> i64 {
+    days_from_ymd(year, 1, 1) + i64::from(ddd) - 1
+}
+
+pub(crate) fn ordinal_from_days(days: i64) -> Option<(i32, u32)> {
+    let (year, _, _) = ymd_from_days(days)?;
+    let ddd = days - days_from_ymd(year, 1, 1) + 1;
+    Some((year, ddd as u32))
+}
+
+// ISO day of week, 1 being Monday and 7 being Sunday.
+fn weekday(days: i64) -> u32 {
+    // 1970-01-01 was a Thursday
+    ((days + 3).rem_euclid(7) + 1) as u32
+}
+
+// Week 1 is the week with the year's first Thursday, i.e. the one containing January 4th.
+fn first_monday_of_iso_year(year: i64) -> i64 {
+    let jan_4 = days_from_civil(year, 1, 4);
+    jan_4 - i64::from(weekday(jan_4)) + 1
+}
+
+pub(crate) fn days_from_iso_week(year: i32, ww: u32, d: u32) -> i64 {
+    first_monday_of_iso_year(i64::from(year)) + i64::from(ww - 1) * 7 + i64::from(d) - 1
+}
+
+// `None` if the ISO year doesn't fit an `i32`, even if the calendar year does.
+pub(crate) fn iso_week_from_days(days: i64) -> Option<(i32, u32, u32)> {
+    if !days_in_range(days) {
+        return None;
+    }
+    let (year, _, _) = civil_from_days(days);
+    // the ISO year may start in the previous or end in the next calendar year
+    let year = if days < first_monday_of_iso_year(year) {
+        year - 1
+    } else if days >= first_monday_of_iso_year(year + 1) {
+        year + 1
+    } else {
+        year
+    };
+    let ww = (days - first_monday_of_iso_year(year)) / 7 + 1;
+    Some((i32::try_from(year).ok()?, ww as u32, weekday(days)))
+}
+
+// The months at whose end a leap second was inserted, as published in the IERS Bulletin C.
+const LEAP_SECOND_MONTHS: [(i32, u32); 27] = [
+    (1972, 6),
+    (1972, 12),
+    (1973, 12),
+    (1974, 12),
+    (1975, 12),
+    (1976, 12),
+    (1977, 12),
+    (1978, 12),
+    (1979, 12),
+    (1981, 6),
+    (1982, 6),
+    (1983, 6),
+    (1985, 6),
+    (1987, 12),
+    (1989, 12),
+    (1990, 12),
+    (1992, 6),
+    (1993, 6),
+    (1994, 6),
+    (1995, 12),
+    (1997, 6),
+    (1998, 12),
+    (2005, 12),
+    (2008, 12),
+    (2012, 6),
+    (2015, 6),
+    (2016, 12),
+];
+
+// Whether a leap second was inserted at the end of the UTC day `days` after 1970-01-01.
+pub(crate) fn is_leap_second_day(days: i64) -> bool {
+    let Some((year, month, day)) = ymd_from_days(days) else {
+        return false;
+    };
+    day == days_in_month(year, month) && LEAP_SECOND_MONTHS.contains(&(year, month))
+}
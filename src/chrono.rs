@@ -17,6 +17,11 @@ impl TryFrom<crate::Date> for chrono::NaiveDate {
                 .and_then(|d| chrono::NaiveDate::from_isoywd_opt(year, ww, d)),
 
             crate::Date::Ordinal { year, ddd } => chrono::NaiveDate::from_yo_opt(year, ddd),
+
+            crate::Date::YearMonth { .. }
+            | crate::Date::YearWeek { .. }
+            | crate::Date::Year { .. }
+            | crate::Date::Century { .. } => None,
         };
         maybe.ok_or(())
     }
@@ -52,6 +57,35 @@ mod test_date {
         assert_eq!(naive.day(), 8);
     }
 
+    #[test]
+    fn conversions_agree_with_chrono() {
+        let mut naive = chrono::NaiveDate::from_ymd_opt(1998, 1, 1).unwrap();
+        while naive.year() < 2030 {
+            let iso = crate::Date::YMD {
+                year: naive.year(),
+                month: naive.month(),
+                day: naive.day(),
+            };
+            let week = naive.iso_week();
+            assert_eq!(
+                iso.to_week(),
+                Some(crate::Date::Week {
+                    year: week.year(),
+                    ww: week.week(),
+                    d: naive.weekday().number_from_monday()
+                })
+            );
+            assert_eq!(
+                iso.to_ordinal(),
+                Some(crate::Date::Ordinal {
+                    year: naive.year(),
+                    ddd: naive.ordinal()
+                })
+            );
+            naive = naive.succ_opt().unwrap();
+        }
+    }
+
     #[test]
     fn naivedate_from_ordinal() {
         let iso = crate::date("2023-039").unwrap();
@@ -65,8 +99,12 @@ mod test_date {
 impl TryFrom<crate::Time> for chrono::NaiveTime {
     type Error = ();
     fn try_from(iso: crate::Time) -> Result<Self, Self::Error> {
-        chrono::NaiveTime::from_hms_milli_opt(iso.hour, iso.minute, iso.second, iso.millisecond)
-            .ok_or(())
+        // chrono has a leap second continue the second 59 beyond a billion nanoseconds
+        let (second, nanosecond) = match iso.second {
+            60 => (59, iso.nanosecond.checked_add(1_000_000_000).ok_or(())?),
+            second => (second, iso.nanosecond),
+        };
+        chrono::NaiveTime::from_hms_nano_opt(iso.hour, iso.minute, second, nanosecond).ok_or(())
     }
 }
 
@@ -77,38 +115,85 @@ impl crate::Time {
     }
 }
 
-impl TryFrom<crate::DateTime> for chrono::DateTime<chrono::FixedOffset> {
+impl From<crate::UtcOffset> for chrono::FixedOffset {
+    fn from(iso: crate::UtcOffset) -> Self {
+        // within ±18:00, well inside chrono's range of less than a day
+        chrono::FixedOffset::east_opt(iso.total_seconds()).unwrap()
+    }
+}
+
+impl TryFrom<chrono::FixedOffset> for crate::UtcOffset {
     type Error = ();
 
-    fn try_from(iso: crate::DateTime) -> Result<Self, Self::Error> {
-        let crate::Time {
-            tz_offset_hours,
-            tz_offset_minutes,
-            ..
-        } = iso.time;
+    /// Fails for offsets of more than 18 hours.
+    fn try_from(offset: chrono::FixedOffset) -> Result<Self, Self::Error> {
+        crate::UtcOffset::from_seconds(offset.local_minus_utc()).ok_or(())
+    }
+}
 
-        let offset_seconds = tz_offset_hours * 3600 + tz_offset_minutes * 60;
-        let offset = chrono::FixedOffset::east_opt(offset_seconds).ok_or(())?;
+#[cfg(test)]
+mod test_utc_offset {
+    use core::convert::TryFrom;
+
+    #[test]
+    fn fixed_offset_from_utc_offset() {
+        let iso = crate::UtcOffset::from_hms(-5, -30, 0).unwrap();
+        let offset = chrono::FixedOffset::from(iso);
+        assert_eq!(offset.local_minus_utc(), -19800);
+        assert_eq!(crate::UtcOffset::try_from(offset), Ok(iso));
+    }
+
+    #[test]
+    fn utc_offset_with_seconds() {
+        let iso: crate::UtcOffset = "+00:17:30".parse().unwrap();
+        assert_eq!(chrono::FixedOffset::from(iso).local_minus_utc(), 1050);
+    }
+
+    #[test]
+    fn out_of_range() {
+        let offset = chrono::FixedOffset::east_opt(19 * 3600).unwrap();
+        assert_eq!(crate::UtcOffset::try_from(offset), Err(()));
+    }
+}
+
+impl TryFrom<crate::DateTime> for chrono::NaiveDateTime {
+    type Error = ();
 
+    /// The local date and time, regardless of the offset.
+    ///
+    /// `24:00:00` is the midnight of the next day, see [`crate::DateTime::normalize`].
+    fn try_from(iso: crate::DateTime) -> Result<Self, Self::Error> {
+        let iso = iso.normalize().ok_or(())?;
         let naive_time = chrono::NaiveTime::try_from(iso.time)?;
-        let naive_date_time = chrono::NaiveDate::try_from(iso.date)?.and_time(naive_time);
+        Ok(chrono::NaiveDate::try_from(iso.date)?.and_time(naive_time))
+    }
+}
+
+impl TryFrom<crate::DateTime> for chrono::DateTime<chrono::FixedOffset> {
+    type Error = ();
+
+    /// Fails for a local time, which has no offset, see [`crate::Offset::Local`].
+    fn try_from(iso: crate::DateTime) -> Result<Self, Self::Error> {
+        let offset_seconds = iso.time.offset.utc_offset_seconds().ok_or(())?;
+        let offset = chrono::FixedOffset::east_opt(offset_seconds).ok_or(())?;
 
         offset
-            .from_local_datetime(&naive_date_time)
+            .from_local_datetime(&chrono::NaiveDateTime::try_from(iso)?)
             .single()
             .ok_or(())
     }
 }
 
 impl crate::DateTime {
-    /// create a [`chrono::DateTime<chrono::FixedOffset>`] if possible
+    /// create a [`chrono::DateTime<chrono::FixedOffset>`] if possible,
+    /// which requires an offset
     pub fn into_fixed_offset(self) -> Option<chrono::DateTime<chrono::FixedOffset>> {
         chrono::DateTime::<chrono::FixedOffset>::try_from(self).ok()
     }
 
     /// create a [`chrono::NativeDateTime`] if possible
     pub fn into_naive(self) -> Option<chrono::NaiveDateTime> {
-        self.into_fixed_offset().map(|fxed| fxed.naive_local())
+        chrono::NaiveDateTime::try_from(self).ok()
     }
 }
 
@@ -148,7 +233,7 @@ mod test_datetime {
     #[test]
     fn datetime_from_iso_ymd_no_offset() {
         let iso = crate::datetime("2023-02-08T23:40:00").unwrap();
-        let datetime = chrono::DateTime::try_from(iso).unwrap();
+        let datetime = chrono::NaiveDateTime::try_from(iso).unwrap();
 
         assert_eq!(datetime.year(), 2023);
         assert_eq!(datetime.month(), 2);
@@ -156,9 +241,57 @@ mod test_datetime {
         assert_eq!(datetime.hour(), 23);
         assert_eq!(datetime.minute(), 40);
         assert_eq!(datetime.second(), 00);
+
+        // a local time has no offset
+        assert_eq!(iso.into_fixed_offset(), None);
+    }
+
+    #[test]
+    fn datetime_from_iso_ymd_unknown_offset() {
+        let iso = crate::datetime("2023-02-08T23:40:00-00:00").unwrap();
+        let datetime = chrono::DateTime::try_from(iso).unwrap();
+
+        assert_eq!(datetime.hour(), 23);
         assert_eq!(datetime.offset().fix().local_minus_utc(), 0);
     }
 
+    #[test]
+    fn naive_datetime_ignores_offset() {
+        let iso = crate::datetime("2023-02-08T23:40:00+01:23").unwrap();
+        let naive = iso.into_naive().unwrap();
+
+        assert_eq!(naive.day(), 8);
+        assert_eq!(naive.hour(), 23);
+    }
+
+    #[test]
+    fn datetime_with_leap_second() {
+        let iso = crate::datetime("2016-12-31T23:59:60.5Z").unwrap();
+        let datetime = iso.into_fixed_offset().unwrap();
+
+        assert_eq!(datetime.day(), 31);
+        assert_eq!(datetime.hour(), 23);
+        assert_eq!(datetime.minute(), 59);
+        assert_eq!(datetime.second(), 59);
+        assert_eq!(datetime.nanosecond(), 1_500_000_000);
+
+        let iso = crate::datetime("2017-01-01T00:59:60+01:00").unwrap();
+        let datetime = iso.into_fixed_offset().unwrap();
+        assert_eq!(datetime.second(), 59);
+        assert_eq!(datetime.nanosecond(), 1_000_000_000);
+    }
+
+    #[test]
+    fn datetime_at_end_of_day() {
+        let iso = crate::datetime("2023-02-28T24:00:00+01:00").unwrap();
+        let datetime = iso.into_fixed_offset().unwrap();
+
+        assert_eq!(datetime.month(), 3);
+        assert_eq!(datetime.day(), 1);
+        assert_eq!(datetime.hour(), 0);
+        assert_eq!(datetime.offset().fix().local_minus_utc(), 3600);
+    }
+
     #[test]
     fn datetime_from_iso_ywd() {
         let iso = crate::datetime("2023-W06-2T23:40:00+01:23").unwrap();
@@ -173,6 +306,17 @@ mod test_datetime {
         assert_eq!(datetime.offset().fix().local_minus_utc(), 4980);
     }
 
+    #[test]
+    fn naivetime_from_hms_nano() {
+        let iso = crate::time("23:40:15.123456789").unwrap();
+        let naive = chrono::NaiveTime::try_from(iso).unwrap();
+
+        assert_eq!(naive.hour(), 23);
+        assert_eq!(naive.minute(), 40);
+        assert_eq!(naive.second(), 15);
+        assert_eq!(naive.nanosecond(), 123_456_789);
+    }
+
     #[test]
     fn naivetime_from_hms_milli() {
         let iso = crate::time("23:40:15.123").unwrap();
@@ -184,6 +328,15 @@ mod test_datetime {
         assert_eq!(naive.nanosecond(), 123_000_000);
     }
 
+    #[test]
+    fn naivetime_with_leap_second() {
+        let iso = crate::time("23:59:60.25").unwrap();
+        let naive = chrono::NaiveTime::try_from(iso).unwrap();
+
+        assert_eq!(naive.second(), 59);
+        assert_eq!(naive.nanosecond(), 1_250_000_000);
+    }
+
     #[test]
     fn datetime_from_iso_ordinal() {
         let iso = crate::datetime("2023-039T23:40:00+01:23").unwrap();
@@ -226,3 +379,230 @@ mod test_datetime {
         assert_eq!(datetime.offset().fix().local_minus_utc(), -19800);
     }
 }
+
+impl TryFrom<crate::Duration> for chrono::Duration {
+    type Error = ();
+
+    /// Takes a year to be 365 days and a month 30 days, like the conversion into
+    /// [`core::time::Duration`], but keeps the sign of negative durations.
+    fn try_from(iso: crate::Duration) -> Result<Self, Self::Error> {
+        let nanos = iso.nominal_nanos();
+        let seconds = i64::try_from(nanos.div_euclid(1_000_000_000)).map_err(|_| ())?;
+        let nanoseconds = nanos.rem_euclid(1_000_000_000) as i64;
+        chrono::Duration::try_seconds(seconds)
+            .and_then(|d| d.checked_add(&chrono::Duration::nanoseconds(nanoseconds)))
+            .ok_or(())
+    }
+}
+
+impl crate::Duration {
+    /// create a [`chrono::Duration`] if possible
+    pub fn into_time_delta(&self) -> Option<chrono::Duration> {
+        chrono::Duration::try_from(*self).ok()
+    }
+}
+
+#[cfg(test)]
+mod test_duration {
+    #[test]
+    fn duration() {
+        let iso = crate::duration("P1DT2H30.5S").unwrap();
+        let expected = chrono::Duration::hours(26) + chrono::Duration::milliseconds(30_500);
+        assert_eq!(iso.into_time_delta(), Some(expected));
+    }
+
+    #[test]
+    fn negative_duration() {
+        let iso = crate::duration("-PT1.5S").unwrap();
+        assert_eq!(
+            iso.into_time_delta(),
+            Some(chrono::Duration::milliseconds(-1500))
+        );
+
+        let iso = crate::duration("P1DT-1H").unwrap();
+        assert_eq!(iso.into_time_delta(), Some(chrono::Duration::hours(23)));
+    }
+
+    #[test]
+    fn weeks_and_days() {
+        let iso = crate::duration("P1W2DT12H").unwrap();
+        let expected = chrono::Duration::days(9) + chrono::Duration::hours(12);
+        assert_eq!(iso.into_time_delta(), Some(expected));
+    }
+
+    #[test]
+    fn out_of_range() {
+        let iso = crate::duration("P2000000000Y").unwrap();
+        assert_eq!(iso.into_time_delta(), None);
+    }
+}
+
+// Moves `datetime` by `duration`, or by its negation if not `forward`,
+// forwards by the calendar units first and backwards by the clock units first,
+// so both directions mirror each other.
+fn shift(
+    datetime: chrono::DateTime<chrono::FixedOffset>,
+    duration: crate::Duration,
+    forward: bool,
+) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+    let duration = if forward { duration } else { -duration };
+    let (months, days, clock) = match duration {
+        crate::Duration::YMDHMS {
+            year,
+            month,
+            week,
+            day,
+            hour,
+            minute,
+            second,
+            nanosecond,
+        } => {
+            let seconds = i64::from(hour) * 3600 + i64::from(minute) * 60 + i64::from(second);
+            let clock = chrono::Duration::seconds(seconds)
+                + chrono::Duration::nanoseconds(i64::from(nanosecond));
+            (
+                i64::from(year) * 12 + i64::from(month),
+                i64::from(week) * 7 + i64::from(day),
+                clock,
+            )
+        }
+        crate::Duration::Weeks(weeks) => (0, i64::from(weeks) * 7, chrono::Duration::zero()),
+    };
+
+    let add_months = |datetime: chrono::DateTime<chrono::FixedOffset>| {
+        let n = chrono::Months::new(u32::try_from(months.unsigned_abs()).ok()?);
+        if months < 0 {
+            datetime.checked_sub_months(n)
+        } else {
+            datetime.checked_add_months(n)
+        }
+    };
+    let add_days = |datetime: chrono::DateTime<chrono::FixedOffset>| {
+        let n = chrono::Days::new(days.unsigned_abs());
+        if days < 0 {
+            datetime.checked_sub_days(n)
+        } else {
+            datetime.checked_add_days(n)
+        }
+    };
+
+    if forward {
+        add_months(datetime)
+            .and_then(add_days)?
+            .checked_add_signed(clock)
+    } else {
+        add_months(add_days(datetime.checked_add_signed(clock)?)?)
+    }
+}
+
+impl TryFrom<crate::Interval> for core::ops::Range<chrono::DateTime<chrono::FixedOffset>> {
+    type Error = ();
+
+    fn try_from(iso: crate::Interval) -> Result<Self, Self::Error> {
+        let endpoint = |endpoint| match endpoint {
+            crate::IntervalEndpoint::DateTime(datetime) => {
+                chrono::DateTime::<chrono::FixedOffset>::try_from(datetime)
+            }
+            crate::IntervalEndpoint::Date(_) => Err(()),
+        };
+
+        match iso {
+            crate::Interval::StartEnd { start, end } => Ok(endpoint(start)?..endpoint(end)?),
+            crate::Interval::StartDuration { start, duration } => {
+                let start = endpoint(start)?;
+                Ok(start..shift(start, duration, true).ok_or(())?)
+            }
+            crate::Interval::DurationEnd { duration, end } => {
+                let end = endpoint(end)?;
+                Ok(shift(end, duration, false).ok_or(())?..end)
+            }
+        }
+    }
+}
+
+impl crate::Interval {
+    /// create a range of [`chrono::DateTime<chrono::FixedOffset>`] if possible,
+    /// which requires the endpoints to be datetimes
+    pub fn into_fixed_offset(
+        self,
+    ) -> Option<core::ops::Range<chrono::DateTime<chrono::FixedOffset>>> {
+        core::ops::Range::try_from(self).ok()
+    }
+}
+
+#[cfg(test)]
+mod test_interval {
+    use chrono::TimeZone;
+
+    fn at(
+        offset_seconds: i32,
+        (year, month, day): (i32, u32, u32),
+        (hour, minute): (u32, u32),
+    ) -> chrono::DateTime<chrono::FixedOffset> {
+        chrono::FixedOffset::east_opt(offset_seconds)
+            .unwrap()
+            .with_ymd_and_hms(year, month, day, hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn start_end() {
+        let iso = crate::interval("2007-03-01T13:00:00Z/2008-05-11T15:30:00+02:00").unwrap();
+        let range = iso.into_fixed_offset().unwrap();
+
+        assert_eq!(range.start, at(0, (2007, 3, 1), (13, 0)));
+        assert_eq!(range.end, at(7200, (2008, 5, 11), (15, 30)));
+    }
+
+    #[test]
+    fn start_duration() {
+        let iso = crate::interval("2007-03-01T13:00:00Z/P1Y2M10DT2H30M").unwrap();
+        let range = iso.into_fixed_offset().unwrap();
+
+        assert_eq!(range.end, at(0, (2008, 5, 11), (15, 30)));
+    }
+
+    #[test]
+    fn duration_end() {
+        let iso = crate::interval("P1Y2M10DT2H30M/2008-05-11T15:30:00Z").unwrap();
+        let range = iso.into_fixed_offset().unwrap();
+
+        assert_eq!(range.start, at(0, (2007, 3, 1), (13, 0)));
+    }
+
+    #[test]
+    fn weeks() {
+        let iso = crate::interval("2007-03-01T13:00:00Z/P2W").unwrap();
+        let range = iso.into_fixed_offset().unwrap();
+
+        assert_eq!(range.end, at(0, (2007, 3, 15), (13, 0)));
+    }
+
+    #[test]
+    fn weeks_and_days() {
+        let iso = crate::interval("2007-03-01T13:00:00Z/P1W2DT1H").unwrap();
+        let range = iso.into_fixed_offset().unwrap();
+        assert_eq!(range.end, at(0, (2007, 3, 10), (14, 0)));
+
+        let iso = crate::interval("P1W2DT1H/2007-03-10T14:00:00Z").unwrap();
+        let range = iso.into_fixed_offset().unwrap();
+        assert_eq!(range.start, at(0, (2007, 3, 1), (13, 0)));
+    }
+
+    #[test]
+    fn negative_duration() {
+        let iso = crate::interval("2008-05-11T15:30:00Z/-P1Y2M10DT2H30M").unwrap();
+        let range = iso.into_fixed_offset().unwrap();
+        assert_eq!(range.end, at(0, (2007, 3, 1), (13, 0)));
+
+        let iso = crate::interval("-P1Y2M10DT2H30M/2007-03-01T13:00:00Z").unwrap();
+        let range = iso.into_fixed_offset().unwrap();
+        assert_eq!(range.start, at(0, (2008, 5, 11), (15, 30)));
+    }
+
+    #[test]
+    fn date_endpoints() {
+        let iso = crate::interval("2008-02-15/03-14").unwrap();
+        assert_eq!(iso.into_fixed_offset(), None);
+    }
+}
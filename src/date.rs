@@ -1,15 +1,25 @@
-use alloc::string::String;
-use core::str::FromStr;
+use core::{
+    fmt::{self, Display},
+    str::FromStr,
+};
 
-use crate::parsers;
+use crate::{
+    calendar::{self, days_in_month, days_in_year, is_leap_year, iso_weeks_in_year},
+    parsers, ParseError,
+};
 
-/// A date, can hold three different formats.
+/// A date, can hold three different complete formats
+/// and four formats of reduced precision.
 /// ```
 /// # use std::str::FromStr;
 /// assert_eq!(
-///     iso8601::Date::from_str("2023-02-18T17:08:08.793Z"),
+///     iso8601::Date::from_str("2023-02-18"),
 ///     Ok(iso8601::Date::YMD{ year: 2023, month: 2, day: 18})
-/// )
+/// );
+/// assert_eq!(
+///     iso8601::Date::from_str("2023-02"),
+///     Ok(iso8601::Date::YearMonth{ year: 2023, month: 2 })
+/// );
 /// ```
 #[allow(missing_docs)]
 #[derive(Eq, PartialEq, Debug, Copy, Clone)]
@@ -20,6 +30,19 @@ pub enum Date {
     Week { year: i32, ww: u32, d: u32 },
     /// consists of year and day of year
     Ordinal { year: i32, ddd: u32 },
+    /// reduced precision, consists of year and month
+    YearMonth { year: i32, month: u32 },
+    /// reduced precision, consists of year and week
+    YearWeek { year: i32, ww: u32 },
+    /// reduced precision, consists of the year only
+    Year { year: i32 },
+    /// reduced precision, consists of the first two digits of the year,
+    /// e.g. `20` for the years 2000 to 2099, or `+123` for the years 12300 to 12399
+    ///
+    /// A century of four digits or more is written like a year,
+    /// it only parses back with an agreed number of digits,
+    /// see [`Date::display_expanded`] and [`ParseOptions::expanded_year`][`crate::ParseOptions::expanded_year`].
+    Century { century: i32 },
 }
 
 impl Default for Date {
@@ -32,8 +55,191 @@ impl Default for Date {
     }
 }
 
+impl Date {
+    /// Whether this date exists in the (proleptic Gregorian) calendar.
+    ///
+    /// See [`Date::validate`] for the rules that are checked.
+    ///
+    /// ```
+    /// assert!(iso8601::date("2016-02-29").unwrap().is_valid());
+    /// assert!(!iso8601::date("2015-02-29").unwrap().is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Checks that this date exists in the (proleptic Gregorian) calendar.
+    ///
+    /// The parsers only check each component on its own,
+    /// so `2015-02-29`, `2015-366` and `2014-W53-1` all parse.
+    /// This additionally checks the length of the month,
+    /// leap years and whether the year has 53 ISO weeks.
+    ///
+    /// ```
+    /// use iso8601::InvalidDate;
+    ///
+    /// let date = iso8601::date("2015-04-31").unwrap();
+    /// assert_eq!(date.validate(), Err(InvalidDate::DayOfMonth));
+    /// ```
+    pub fn validate(&self) -> Result<(), InvalidDate> {
+        match *self {
+            Date::YMD { year, month, day } => {
+                if !(1..=12).contains(&month) {
+                    Err(InvalidDate::Month)
+                } else if month == 2 && day == 29 && !is_leap_year(year) {
+                    Err(InvalidDate::LeapDay)
+                } else if day < 1 || day > days_in_month(year, month) {
+                    Err(InvalidDate::DayOfMonth)
+                } else {
+                    Ok(())
+                }
+            }
+            Date::Week { year, ww, d } => {
+                if ww < 1 || ww > iso_weeks_in_year(year) {
+                    Err(InvalidDate::Week)
+                } else if !(1..=7).contains(&d) {
+                    Err(InvalidDate::WeekDay)
+                } else {
+                    Ok(())
+                }
+            }
+            Date::Ordinal { year, ddd } => {
+                if ddd < 1 || ddd > days_in_year(year) {
+                    Err(InvalidDate::DayOfYear)
+                } else {
+                    Ok(())
+                }
+            }
+            Date::YearMonth { month, .. } => {
+                if !(1..=12).contains(&month) {
+                    Err(InvalidDate::Month)
+                } else {
+                    Ok(())
+                }
+            }
+            Date::YearWeek { year, ww } => {
+                if ww < 1 || ww > iso_weeks_in_year(year) {
+                    Err(InvalidDate::Week)
+                } else {
+                    Ok(())
+                }
+            }
+            Date::Year { .. } | Date::Century { .. } => Ok(()),
+        }
+    }
+
+    /// Converts this date into the [`Date::YMD`] representation.
+    ///
+    /// Returns `None` if the date doesn't exist, see [`Date::validate`],
+    /// or is of reduced precision.
+    ///
+    /// ```
+    /// let date = iso8601::date("2015-W45-1").unwrap();
+    /// assert_eq!(date.to_ymd(), Some(iso8601::Date::YMD { year: 2015, month: 11, day: 2 }));
+    /// ```
+    pub fn to_ymd(&self) -> Option<Date> {
+        let (year, month, day) = calendar::ymd_from_days(self.days()?)?;
+        Some(Date::YMD { year, month, day })
+    }
+
+    /// Converts this date into the [`Date::Week`] representation.
+    ///
+    /// The year of the result is the ISO week-numbering year,
+    /// which differs from the calendar year for some days around New Year.
+    /// Returns `None` if the date doesn't exist, see [`Date::validate`],
+    /// or is of reduced precision.
+    ///
+    /// ```
+    /// let date = iso8601::date("2021-01-03").unwrap();
+    /// assert_eq!(date.to_week(), Some(iso8601::Date::Week { year: 2020, ww: 53, d: 7 }));
+    /// ```
+    pub fn to_week(&self) -> Option<Date> {
+        let (year, ww, d) = calendar::iso_week_from_days(self.days()?)?;
+        Some(Date::Week { year, ww, d })
+    }
+
+    /// Converts this date into the [`Date::Ordinal`] representation.
+    ///
+    /// Returns `None` if the date doesn't exist, see [`Date::validate`],
+    /// or is of reduced precision.
+    ///
+    /// ```
+    /// let date = iso8601::date("2015-11-02").unwrap();
+    /// assert_eq!(date.to_ordinal(), Some(iso8601::Date::Ordinal { year: 2015, ddd: 306 }));
+    /// ```
+    pub fn to_ordinal(&self) -> Option<Date> {
+        let (year, ddd) = calendar::ordinal_from_days(self.days()?)?;
+        Some(Date::Ordinal { year, ddd })
+    }
+
+    // Days since 1970-01-01, if the date exists and is complete.
+    pub(crate) fn days(&self) -> Option<i64> {
+        self.validate().ok()?;
+
+        match *self {
+            Date::YMD { year, month, day } => Some(calendar::days_from_ymd(year, month, day)),
+            Date::Week { year, ww, d } => Some(calendar::days_from_iso_week(year, ww, d)),
+            Date::Ordinal { year, ddd } => Some(calendar::days_from_ordinal(year, ddd)),
+            Date::YearMonth { .. }
+            | Date::YearWeek { .. }
+            | Date::Year { .. }
+            | Date::Century { .. } => None,
+        }
+    }
+
+    // The date `days` after 1970-01-01 in the representation of this date,
+    // calendar dates for the ones of reduced precision.
+    pub(crate) fn with_days(&self, days: i64) -> Option<Date> {
+        Some(match self {
+            Date::Week { .. } => {
+                let (year, ww, d) = calendar::iso_week_from_days(days)?;
+                Date::Week { year, ww, d }
+            }
+            Date::Ordinal { .. } => {
+                let (year, ddd) = calendar::ordinal_from_days(days)?;
+                Date::Ordinal { year, ddd }
+            }
+            _ => {
+                let (year, month, day) = calendar::ymd_from_days(days)?;
+                Date::YMD { year, month, day }
+            }
+        })
+    }
+}
+
+/// The calendar rule violated by a [`Date`] that doesn't exist.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum InvalidDate {
+    /// The month is not within `1..=12`
+    Month,
+    /// The day is beyond the last day of its month, e.g. April 31st
+    DayOfMonth,
+    /// February 29th in a common year
+    LeapDay,
+    /// The ordinal day is beyond the last day of its year, e.g. day 366 in a common year
+    DayOfYear,
+    /// The week is beyond the last ISO week of its year, e.g. week 53 in a 52-week year
+    Week,
+    /// The day of week is not within `1..=7`
+    WeekDay,
+}
+
+impl Display for InvalidDate {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            InvalidDate::Month => "month does not exist",
+            InvalidDate::DayOfMonth => "day does not exist in month",
+            InvalidDate::LeapDay => "February 29th in a common year",
+            InvalidDate::DayOfYear => "day does not exist in year",
+            InvalidDate::Week => "week does not exist in year",
+            InvalidDate::WeekDay => "day of week does not exist",
+        };
+        f.write_str(description)
+    }
+}
+
 impl FromStr for Date {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         date(s)
@@ -48,15 +254,164 @@ impl FromStr for Date {
 /// * `2015-W45-01` or `2015W451`
 /// * `2015-306` or `2015306`
 ///
+/// or be of reduced precision:
+///
+/// * `2015-W45` or `2015W45`
+/// * `2015-11`
+/// * `2015`
+/// * `20` (the century)
+///
 /// ## Example
 ///
 /// ```rust
 /// let date = iso8601::date("2015-11-02").unwrap();
 /// ```
-pub fn date(string: &str) -> Result<Date, String> {
-    if let Ok((_, parsed)) = parsers::parse_date(string.as_bytes()) {
-        Ok(parsed)
-    } else {
-        Err(format!("Failed to parse date: {}", string))
+pub fn date(string: &str) -> Result<Date, ParseError> {
+    ParseError::parse_all(string, parsers::parse_date)
+}
+
+/// A truncated date of ISO 8601:2000, which leaves out its leading components.
+///
+/// The left out components are implied by the context,
+/// see [`TruncatedDate::complete`] and [`TruncatedDate::complete_with_pivot`].
+/// ```
+/// # use std::str::FromStr;
+/// assert_eq!(
+///     iso8601::TruncatedDate::from_str("--11-02"),
+///     Ok(iso8601::TruncatedDate::MonthDay { month: 11, day: 2 })
+/// );
+/// ```
+#[allow(missing_docs)]
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum TruncatedDate {
+    /// consists of the year within an implied century, month and day of month
+    YearOfCentury { yy: u32, month: u32, day: u32 },
+    /// consists of month and day of month of an implied year
+    MonthDay { month: u32, day: u32 },
+    /// consists of the month of an implied year
+    Month { month: u32 },
+    /// consists of the day of an implied month
+    Day { day: u32 },
+    /// consists of week and day of week of an implied year
+    WeekDay { ww: u32, d: u32 },
+    /// consists of the week of an implied year
+    Week { ww: u32 },
+    /// consists of the day of an implied week
+    DayOfWeek { d: u32 },
+    /// consists of the day of an implied year
+    Ordinal { ddd: u32 },
+}
+
+impl TruncatedDate {
+    /// Completes this date with the components implied by `reference`.
+    ///
+    /// A two-digit year is taken to be in the century of the reference,
+    /// weeks are taken from its ISO week-numbering year.
+    /// Calendar dates are completed into [`Date::YMD`], weeks into [`Date::Week`]
+    /// and [`Date::YearWeek`], a month into [`Date::YearMonth`]
+    /// and an ordinal day into [`Date::Ordinal`].
+    ///
+    /// Returns `None` if the reference is not a complete date that exists,
+    /// or if the completed date doesn't exist, e.g. `---31` in April
+    /// or `99-01-01` in the century of the year `i32::MAX`.
+    ///
+    /// ```
+    /// use iso8601::{Date, TruncatedDate};
+    ///
+    /// let reference = iso8601::date("2015-06-26").unwrap();
+    /// let date = iso8601::truncated_date("---02").unwrap();
+    /// assert_eq!(date.complete(&reference), Some(Date::YMD { year: 2015, month: 6, day: 2 }));
+    /// let date = iso8601::truncated_date("-W45-1").unwrap();
+    /// assert_eq!(date.complete(&reference), Some(Date::Week { year: 2015, ww: 45, d: 1 }));
+    /// ```
+    pub fn complete(&self, reference: &Date) -> Option<Date> {
+        let days = reference.days()?;
+        let (year, month, _) = calendar::ymd_from_days(days)?;
+        let (week_year, week, _) = calendar::iso_week_from_days(days)?;
+
+        let date = match *self {
+            TruncatedDate::YearOfCentury { yy, month, day } => Date::YMD {
+                year: i32::try_from(
+                    i64::from(year) - i64::from(year.rem_euclid(100)) + i64::from(yy),
+                )
+                .ok()?,
+                month,
+                day,
+            },
+            TruncatedDate::MonthDay { month, day } => Date::YMD { year, month, day },
+            TruncatedDate::Month { month } => Date::YearMonth { year, month },
+            TruncatedDate::Day { day } => Date::YMD { year, month, day },
+            TruncatedDate::WeekDay { ww, d } => Date::Week {
+                year: week_year,
+                ww,
+                d,
+            },
+            TruncatedDate::Week { ww } => Date::YearWeek {
+                year: week_year,
+                ww,
+            },
+            TruncatedDate::DayOfWeek { d } => Date::Week {
+                year: week_year,
+                ww: week,
+                d,
+            },
+            TruncatedDate::Ordinal { ddd } => Date::Ordinal { year, ddd },
+        };
+        date.is_valid().then_some(date)
+    }
+
+    /// Completes a date with a two-digit year into the window of the hundred years
+    /// starting with `pivot`.
+    ///
+    /// With a pivot of `1950`, `49-01-01` is in 2049 and `50-01-01` in 1950.
+    ///
+    /// Returns `None` for the other truncated dates, which have no year,
+    /// and if the completed date doesn't exist.
+    ///
+    /// ```
+    /// use iso8601::{Date, TruncatedDate};
+    ///
+    /// let date = iso8601::truncated_date("491102").unwrap();
+    /// assert_eq!(date.complete_with_pivot(1950), Some(Date::YMD { year: 2049, month: 11, day: 2 }));
+    /// assert_eq!(date.complete_with_pivot(1900), Some(Date::YMD { year: 1949, month: 11, day: 2 }));
+    /// ```
+    pub fn complete_with_pivot(&self, pivot: i32) -> Option<Date> {
+        let TruncatedDate::YearOfCentury { yy, month, day } = *self else {
+            return None;
+        };
+        let year = pivot.checked_add((yy as i32 - pivot).rem_euclid(100))?;
+
+        let date = Date::YMD { year, month, day };
+        date.is_valid().then_some(date)
+    }
+}
+
+impl FromStr for TruncatedDate {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        truncated_date(s)
     }
 }
+
+/// Parses a truncated date string of ISO 8601:2000.
+///
+/// A string can have one of the following formats:
+///
+/// * `15-11-02` or `151102`, a year within an implied century
+/// * `--11-02` or `--1102`, a month and day
+/// * `--11`, a month
+/// * `---02`, a day
+/// * `-W45-1` or `-W451`, a week and day
+/// * `-W45`, a week
+/// * `-W-1`, a day of week
+/// * `-306`, a day of year
+///
+/// ## Example
+///
+/// ```rust
+/// let date = iso8601::truncated_date("--11-02").unwrap();
+/// ```
+pub fn truncated_date(string: &str) -> Result<TruncatedDate, ParseError> {
+    ParseError::parse_all(string, parsers::parse_truncated_date)
+}
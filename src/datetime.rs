@@ -1,7 +1,9 @@
-use alloc::string::String;
 use core::str::FromStr;
 
-use crate::{parsers, Date, Time};
+use crate::{calendar, parsers, Date, Duration, ParseError, Time};
+
+const NANOS_PER_SECOND: i128 = 1_000_000_000;
+const NANOS_PER_DAY: i128 = 86_400 * NANOS_PER_SECOND;
 
 /// Compound struct, holds Date and Time.
 /// ```
@@ -10,7 +12,14 @@ use crate::{parsers, Date, Time};
 ///     iso8601::DateTime::from_str("2023-02-18T17:08:08.793Z"),
 ///     Ok(iso8601::DateTime {
 ///         date: iso8601::Date::YMD{ year: 2023, month: 2, day: 18},
-///         time: iso8601::Time{ hour: 17, minute: 8, second: 8, millisecond: 793, tz_offset_hours: 0, tz_offset_minutes: 00 }
+///         time: iso8601::Time {
+///             hour: 17,
+///             minute: 8,
+///             second: 8,
+///             nanosecond: 793_000_000,
+///             offset: iso8601::Offset::Utc,
+///             precision: iso8601::TimePrecision::Second,
+///         }
 ///     })
 /// )
 /// ```
@@ -22,8 +31,120 @@ pub struct DateTime {
     pub time: Time,
 }
 
+impl DateTime {
+    /// Rolls the end of day `24:00:00` over to `00:00:00` of the next day.
+    ///
+    /// Week and ordinal dates keep their representation,
+    /// any other datetime is returned unchanged.
+    /// Returns `None` for `24:00:00` if the date doesn't exist or is of reduced precision.
+    ///
+    /// ```
+    /// let end_of_day = iso8601::datetime("2015-W53-7T24:00:00Z").unwrap();
+    /// assert_eq!(end_of_day.normalize().unwrap().to_string(), "2016-W01-1T00:00:00.000Z");
+    /// ```
+    pub fn normalize(&self) -> Option<DateTime> {
+        if self.time.hour == 24 {
+            self.add_nanoseconds(0)
+        } else {
+            Some(*self)
+        }
+    }
+
+    // Moves this datetime `times` times by `duration`, by the calendar units first.
+    // The day is clamped to the length of the month, e.g. `2015-01-31` plus `P1M` is `2015-02-28`.
+    // `None` if the date is invalid or of reduced precision, or the result doesn't fit.
+    pub(crate) fn add_duration(&self, duration: &Duration, times: u32) -> Option<DateTime> {
+        let (months, days, nanos) = match *duration {
+            Duration::YMDHMS {
+                year,
+                month,
+                week,
+                day,
+                hour,
+                minute,
+                second,
+                nanosecond,
+            } => {
+                let seconds =
+                    i128::from(hour) * 3600 + i128::from(minute) * 60 + i128::from(second);
+                (
+                    i128::from(year) * 12 + i128::from(month),
+                    i128::from(week) * 7 + i128::from(day),
+                    seconds * NANOS_PER_SECOND + i128::from(nanosecond),
+                )
+            }
+            Duration::Weeks(weeks) => (0, i128::from(weeks) * 7, 0),
+        };
+        let times = i128::from(times);
+
+        let (year, month, day) = calendar::ymd_from_days(self.date.days()?)?;
+        let months = i128::from(year) * 12 + i128::from(month - 1) + months * times;
+        let year = i32::try_from(months.div_euclid(12)).ok()?;
+        let month = (months.rem_euclid(12) + 1) as u32;
+        let day = day.min(calendar::days_in_month(year, month));
+
+        let days = i128::from(calendar::days_from_ymd(year, month, day)) + days * times;
+        self.with_local_nanos(days * NANOS_PER_DAY + self.time_of_day_nanos() + nanos * times)
+    }
+
+    // Moves this datetime by an exact number of nanoseconds.
+    pub(crate) fn add_nanoseconds(&self, nanos: i128) -> Option<DateTime> {
+        let local = i128::from(self.date.days()?) * NANOS_PER_DAY + self.time_of_day_nanos();
+        self.with_local_nanos(local + nanos)
+    }
+
+    // Nanoseconds since 1970-01-01T00:00:00Z, or since the local midnight for a local time.
+    pub(crate) fn timestamp_nanos(&self) -> Option<i128> {
+        let local = i128::from(self.date.days()?) * NANOS_PER_DAY + self.time_of_day_nanos();
+        let offset = self.time.offset.utc_offset_seconds().unwrap_or(0);
+        Some(local - i128::from(offset) * NANOS_PER_SECOND)
+    }
+
+    // Whether this is a leap second of the published table.
+    pub(crate) fn is_known_leap_second(&self) -> bool {
+        self.time.second == 60
+            && self.timestamp_nanos().is_some_and(|nanos| {
+                i64::try_from(nanos.div_euclid(NANOS_PER_DAY))
+                    .is_ok_and(calendar::is_leap_second_day)
+            })
+    }
+
+    fn time_of_day_nanos(&self) -> i128 {
+        let Time {
+            hour,
+            minute,
+            second,
+            nanosecond,
+            ..
+        } = self.time;
+        // a leap second repeats the second before it, so that one second later is the next minute
+        let second = second.min(59);
+        let seconds = i128::from(hour) * 3600 + i128::from(minute) * 60 + i128::from(second);
+        seconds * NANOS_PER_SECOND + i128::from(nanosecond)
+    }
+
+    // The datetime `nanos` after the local midnight of 1970-01-01,
+    // with the offset and date representation of this one.
+    fn with_local_nanos(&self, nanos: i128) -> Option<DateTime> {
+        let days = i64::try_from(nanos.div_euclid(NANOS_PER_DAY)).ok()?;
+        let time_of_day = nanos.rem_euclid(NANOS_PER_DAY);
+        let seconds = (time_of_day / NANOS_PER_SECOND) as u32;
+
+        Some(DateTime {
+            date: self.date.with_days(days)?,
+            time: Time {
+                hour: seconds / 3600,
+                minute: seconds / 60 % 60,
+                second: seconds % 60,
+                nanosecond: (time_of_day % NANOS_PER_SECOND) as u32,
+                ..self.time
+            },
+        })
+    }
+}
+
 impl FromStr for DateTime {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         datetime(s)
@@ -41,10 +162,6 @@ impl FromStr for DateTime {
 /// ```rust
 /// let dt = iso8601::datetime("2015-11-03T21:56").unwrap();
 /// ```
-pub fn datetime(string: &str) -> Result<DateTime, String> {
-    if let Ok((_left_overs, parsed)) = parsers::parse_datetime(string.as_bytes()) {
-        Ok(parsed)
-    } else {
-        Err(format!("Failed to parse datetime: {}", string))
-    }
+pub fn datetime(string: &str) -> Result<DateTime, ParseError> {
+    ParseError::parse_all(string, parsers::parse_datetime)
 }
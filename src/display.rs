@@ -1,56 +1,323 @@
 use core::fmt::{self, Display};
 
-use super::{Date, DateTime, Duration, Time};
+use super::{
+    format::{Format, Notation},
+    Date, DateTime, Duration, Interval, IntervalEndpoint, Offset, RepeatingInterval, Time,
+    TimePrecision, TruncatedDate, UtcOffset,
+};
 
-fn write_year(f: &mut fmt::Formatter, year: i32) -> fmt::Result {
-    if year < 0 {
-        let year = -year;
-        write!(f, "-{year:04}")
+// Years beyond four digits need the sign of the expanded representation,
+// with `extra_digits` every year gets it along with the agreed width.
+fn write_year(
+    f: &mut fmt::Formatter,
+    year: i32,
+    digits: usize,
+    extra_digits: Option<u8>,
+) -> fmt::Result {
+    let sign = if year < 0 { "-" } else { "+" };
+    let abs = year.unsigned_abs();
+    match extra_digits {
+        Some(extra) => {
+            let width = digits + usize::from(extra);
+            write!(f, "{sign}{abs:0width$}")
+        }
+        None if year < 0 || abs >= 10u32.pow(digits as u32) => write!(f, "{sign}{abs:0digits$}"),
+        None => write!(f, "{abs:0digits$}"),
+    }
+}
+
+// Writes the fraction of a second with as many digits as it needs,
+// in groups of three: milli-, micro- or nanoseconds.
+pub(crate) fn write_fraction(f: &mut fmt::Formatter, nanosecond: u32) -> fmt::Result {
+    write_fraction_digits(f, nanosecond, None, '.')
+}
+
+// Writes the fraction of a second after `sign` with `digits` digits, if they hold it,
+// and like `write_fraction` otherwise.
+fn write_fraction_digits(
+    f: &mut fmt::Formatter,
+    nanosecond: u32,
+    digits: Option<u8>,
+    sign: char,
+) -> fmt::Result {
+    let digits = match digits.map(|digits| u32::from(digits.min(9))) {
+        Some(digits) if nanosecond.is_multiple_of(10u32.pow(9 - digits)) => digits,
+        _ if nanosecond.is_multiple_of(1_000_000) => 3,
+        _ if nanosecond.is_multiple_of(1_000) => 6,
+        _ => 9,
+    };
+    if digits == 0 {
+        return Ok(());
+    }
+    let fraction = nanosecond / 10u32.pow(9 - digits);
+    let width = digits as usize;
+    write!(f, "{sign}{fraction:0width$}")
+}
+
+fn write_date(
+    f: &mut fmt::Formatter,
+    date: &Date,
+    extra_digits: Option<u8>,
+    notation: Notation,
+) -> fmt::Result {
+    let sep = match notation {
+        Notation::Basic => "",
+        Notation::Extended => "-",
+    };
+    match *date {
+        Date::YMD { year, month, day } => {
+            write_year(f, year, 4, extra_digits)?;
+            write!(f, "{sep}{month:02}{sep}{day:02}")
+        }
+        Date::Week { year, ww, d } => {
+            write_year(f, year, 4, extra_digits)?;
+            write!(f, "{sep}W{ww:02}{sep}{d:01}")
+        }
+        Date::Ordinal { year, ddd } => {
+            write_year(f, year, 4, extra_digits)?;
+            write!(f, "{sep}{ddd:03}")
+        }
+        // there is no basic format for a year and month, it would be taken for a century
+        Date::YearMonth { year, month } => {
+            write_year(f, year, 4, extra_digits)?;
+            write!(f, "-{month:02}")
+        }
+        Date::YearWeek { year, ww } => {
+            write_year(f, year, 4, extra_digits)?;
+            write!(f, "{sep}W{ww:02}")
+        }
+        Date::Year { year } => write_year(f, year, 4, extra_digits),
+        Date::Century { century } => write_year(f, century, 2, extra_digits),
+    }
+}
+
+fn write_time(f: &mut fmt::Formatter, time: &Time, format: &Format) -> fmt::Result {
+    // down to the precision, unless a lower component would get lost
+    let precision = if (time.second, time.nanosecond) != (0, 0) {
+        TimePrecision::Second
+    } else if time.minute != 0 && time.precision == TimePrecision::Hour {
+        TimePrecision::Minute
     } else {
-        write!(f, "{year:04}")
+        time.precision
+    };
+    let sep = match format.time {
+        Notation::Basic => "",
+        Notation::Extended => ":",
+    };
+
+    // like `16`, `16:43` or `16:43:16.123`, then the offset like `+00:00`
+    write!(f, "{:02}", time.hour)?;
+    if precision != TimePrecision::Hour {
+        write!(f, "{sep}{:02}", time.minute)?;
+    }
+    if precision == TimePrecision::Second {
+        write!(f, "{sep}{:02}", time.second)?;
+        let sign = if format.decimal_comma { ',' } else { '.' };
+        write_fraction_digits(f, time.nanosecond, format.fraction_digits, sign)?;
     }
+    write_offset(f, time.offset, format)
+}
+
+fn write_offset(f: &mut fmt::Formatter, offset: Offset, format: &Format) -> fmt::Result {
+    let offset = match offset {
+        // like `16:43:16.123`
+        Offset::Local => return Ok(()),
+        Offset::Utc => return write!(f, "Z"),
+        Offset::Fixed(offset) => offset,
+        Offset::Unknown => return write_utc_offset(f, '-', UtcOffset::UTC, format),
+    };
+    let sign = if offset.total_seconds() < 0 { '-' } else { '+' };
+    write_utc_offset(f, sign, offset, format)
+}
+
+// Like `+05:30`, or `+00:17:30` with seconds, down to the precision of the format
+// unless a lower component would get lost.
+fn write_utc_offset(
+    f: &mut fmt::Formatter,
+    sign: char,
+    offset: UtcOffset,
+    format: &Format,
+) -> fmt::Result {
+    let sep = match format.offset {
+        Notation::Basic => "",
+        Notation::Extended => ":",
+    };
+    write!(f, "{sign}{:02}", offset.hours().abs())?;
+    if offset.minutes() != 0
+        || offset.seconds() != 0
+        || format.offset_precision != TimePrecision::Hour
+    {
+        write!(f, "{sep}{:02}", offset.minutes().abs())?;
+    }
+    if offset.seconds() != 0 || format.offset_precision == TimePrecision::Second {
+        write!(f, "{sep}{:02}", offset.seconds().abs())?;
+    }
+    Ok(())
 }
 
 impl Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_date(f, self, None, Notation::Extended)
+    }
+}
+
+impl Display for TruncatedDate {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            Date::YMD { year, month, day } => {
-                write_year(f, year)?;
-                write!(f, "-{month:02}-{day:02}")
-            }
-            Date::Week { year, ww, d } => {
-                write_year(f, year)?;
-                write!(f, "-W{ww:02}-{d:01}")
-            }
-            Date::Ordinal { year, ddd } => {
-                write_year(f, year)?;
-                write!(f, "-{ddd:03}")
+            TruncatedDate::YearOfCentury { yy, month, day } => {
+                write!(f, "{yy:02}-{month:02}-{day:02}")
             }
+            TruncatedDate::MonthDay { month, day } => write!(f, "--{month:02}-{day:02}"),
+            TruncatedDate::Month { month } => write!(f, "--{month:02}"),
+            TruncatedDate::Day { day } => write!(f, "---{day:02}"),
+            TruncatedDate::WeekDay { ww, d } => write!(f, "-W{ww:02}-{d:01}"),
+            TruncatedDate::Week { ww } => write!(f, "-W{ww:02}"),
+            TruncatedDate::DayOfWeek { d } => write!(f, "-W-{d:01}"),
+            TruncatedDate::Ordinal { ddd } => write!(f, "-{ddd:03}"),
         }
     }
 }
 
+// Prints the wrapped value with years in the expanded representation.
+struct Expanded<'a, T>(&'a T, u8);
+
+impl Display for Expanded<'_, Date> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_date(f, self.0, Some(self.1), Notation::Extended)
+    }
+}
+
+impl Display for Expanded<'_, DateTime> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_date(f, &self.0.date, Some(self.1), Notation::Extended)?;
+        write!(f, "T{}", self.0.time)
+    }
+}
+
+impl Date {
+    /// Displays this date with the year in the expanded representation,
+    /// with a sign and `extra_digits` digits beyond the usual four.
+    ///
+    /// This is the counterpart of [`ParseOptions::expanded_year`][`crate::ParseOptions::expanded_year`].
+    ///
+    /// ```
+    /// let date = iso8601::date("2015-11-02").unwrap();
+    /// assert_eq!(date.display_expanded(2).to_string(), "+002015-11-02");
+    /// ```
+    pub fn display_expanded(&self, extra_digits: u8) -> impl Display + '_ {
+        Expanded(self, extra_digits)
+    }
+}
+
+impl DateTime {
+    /// Displays this datetime with the year in the expanded representation,
+    /// with a sign and `extra_digits` digits beyond the usual four.
+    ///
+    /// This is the counterpart of [`ParseOptions::expanded_year`][`crate::ParseOptions::expanded_year`].
+    ///
+    /// ```
+    /// let dt = iso8601::datetime("2015-11-02T10:00:00Z").unwrap();
+    /// assert_eq!(dt.display_expanded(1).to_string(), "+02015-11-02T10:00:00.000Z");
+    /// ```
+    pub fn display_expanded(&self, extra_digits: u8) -> impl Display + '_ {
+        Expanded(self, extra_digits)
+    }
+}
+
 impl Display for Time {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        // like `16:43:16.123+00:00`
-        let (sign, tz_offset_hours, tz_offset_minutes) =
-            if self.tz_offset_hours < 0 || self.tz_offset_minutes < 0 {
-                ('-', -self.tz_offset_hours, -self.tz_offset_minutes)
-            } else {
-                ('+', self.tz_offset_hours, self.tz_offset_minutes)
-            };
+        write_time(f, self, &Format::default())
+    }
+}
+
+impl Display for Offset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_offset(f, *self, &Format::default())
+    }
+}
+
+impl Display for UtcOffset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_offset(f, Offset::Fixed(*self), &Format::default())
+    }
+}
+
+// Prints the wrapped value in the given format.
+struct Formatted<'a, T>(&'a T, Format);
+
+impl Display for Formatted<'_, Date> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_date(f, self.0, None, self.1.date)
+    }
+}
+
+impl Display for Formatted<'_, Time> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.1.time_designator {
+            f.write_str("T")?;
+        }
+        write_time(f, self.0, &self.1)
+    }
+}
+
+impl Display for Formatted<'_, DateTime> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_date(f, &self.0.date, None, self.1.date)?;
+        f.write_str("T")?;
+        write_time(f, &self.0.time, &self.1)
+    }
+}
+
+impl Date {
+    /// Displays this date in the given format, like one returned by
+    /// [`format::parse_date`][`crate::format::parse_date`] to write it as it was parsed.
+    ///
+    /// ```
+    /// use iso8601::format::{Format, Notation};
+    ///
+    /// let date = iso8601::date("2015-11-02").unwrap();
+    /// let basic = Format { date: Notation::Basic, ..Format::default() };
+    /// assert_eq!(date.display_with(basic).to_string(), "20151102");
+    /// ```
+    pub fn display_with(&self, format: Format) -> impl Display + '_ {
+        Formatted(self, format)
+    }
+}
+
+impl Time {
+    /// Displays this time in the given format, like one returned by
+    /// [`format::parse_time`][`crate::format::parse_time`] to write it as it was parsed.
+    ///
+    /// ```
+    /// use iso8601::format::Format;
+    ///
+    /// let time = iso8601::time("10:30:00.5").unwrap();
+    /// let format = Format { fraction_digits: Some(1), decimal_comma: true, ..Format::default() };
+    /// assert_eq!(time.display_with(format).to_string(), "10:30:00,5");
+    /// ```
+    pub fn display_with(&self, format: Format) -> impl Display + '_ {
+        Formatted(self, format)
+    }
+}
 
-        write!(
-            f,
-            "{:02}:{:02}:{:02}.{:03}{}{:02}:{:02}",
-            self.hour,
-            self.minute,
-            self.second,
-            self.millisecond,
-            sign,
-            tz_offset_hours,
-            tz_offset_minutes
-        )
+impl DateTime {
+    /// Displays this datetime in the given format, like one returned by
+    /// [`format::parse_datetime`][`crate::format::parse_datetime`] to write it as it was parsed.
+    ///
+    /// ```
+    /// use iso8601::{format::{Format, Notation}, TimePrecision};
+    ///
+    /// let dt = iso8601::datetime("2015-11-02T10:30:00+01:00").unwrap();
+    /// let format = Format {
+    ///     fraction_digits: Some(0),
+    ///     offset: Notation::Basic,
+    ///     offset_precision: TimePrecision::Hour,
+    ///     ..Format::default()
+    /// };
+    /// assert_eq!(dt.display_with(format).to_string(), "2015-11-02T10:30:00+01");
+    /// ```
+    pub fn display_with(&self, format: Format) -> impl Display + '_ {
+        Formatted(self, format)
     }
 }
 
@@ -63,57 +330,104 @@ impl Display for DateTime {
 
 impl Display for Duration {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
+        match *self {
             Duration::YMDHMS {
                 year,
                 month,
+                week,
                 day,
                 hour,
                 minute,
                 second,
-                millisecond,
+                nanosecond,
             } => {
-                if self.is_zero() {
-                    write!(f, "P0D")?;
-                    return Ok(());
+                let nanos = i64::from(second) * 1_000_000_000 + i64::from(nanosecond);
+                let units = [year, month, week, day, hour, minute];
+                if units.iter().all(|&n| n == 0) && nanos == 0 {
+                    return write!(f, "P0D");
                 }
 
-                write!(f, "P")?;
-
-                if *year > 0 {
-                    write!(f, "{}Y", year)?
+                // like `-P1DT2H` if no component is positive, else like `P1DT-2H`
+                let negative = units.iter().all(|&n| n <= 0) && nanos <= 0;
+                let sign = if negative { -1 } else { 1 };
+                if negative {
+                    write!(f, "-")?;
                 }
+                write!(f, "P")?;
 
-                if *month > 0 {
-                    write!(f, "{}M", month)?
+                for (n, unit) in [(year, 'Y'), (month, 'M'), (week, 'W'), (day, 'D')] {
+                    if n != 0 {
+                        write!(f, "{}{}", sign * i64::from(n), unit)?
+                    }
                 }
-
-                if *day > 0 {
-                    write!(f, "{}D", day)?
+                // `P1W` alone is a `Duration::Weeks`
+                if week != 0
+                    && [year, month, day, hour, minute].iter().all(|&n| n == 0)
+                    && nanos == 0
+                {
+                    write!(f, "0D")?
                 }
 
-                if *hour > 0 || *minute > 0 || *second > 0 || *millisecond > 0 {
+                if hour != 0 || minute != 0 || nanos != 0 {
                     write!(f, "T")?
                 }
-                if *hour > 0 {
-                    write!(f, "{}H", hour)?
-                }
-                if *minute > 0 {
-                    write!(f, "{}M", minute)?
+                for (n, unit) in [(hour, 'H'), (minute, 'M')] {
+                    if n != 0 {
+                        write!(f, "{}{}", sign * i64::from(n), unit)?
+                    }
                 }
 
-                if *millisecond > 0 {
-                    write!(f, "{}.{:03}S", second, millisecond)?
-                } else if *second > 0 {
-                    write!(f, "{}S", second)?
+                let nanos = sign * nanos;
+                if nanos % 1_000_000_000 != 0 {
+                    // the seconds may be zero, the sign goes in front of them
+                    if nanos < 0 {
+                        write!(f, "-")?;
+                    }
+                    write!(f, "{}", nanos.unsigned_abs() / 1_000_000_000)?;
+                    write_fraction(f, (nanos.unsigned_abs() % 1_000_000_000) as u32)?;
+                    write!(f, "S")?
+                } else if nanos != 0 {
+                    write!(f, "{}S", nanos / 1_000_000_000)?
                 }
                 Ok(())
             }
+            Duration::Weeks(w) if w < 0 => write!(f, "-P{}W", w.unsigned_abs()),
             Duration::Weeks(w) => write!(f, "P{}W", w),
         }
     }
 }
 
+impl Display for IntervalEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IntervalEndpoint::Date(date) => date.fmt(f),
+            IntervalEndpoint::DateTime(datetime) => datetime.fmt(f),
+        }
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // endpoints are always written out completely, like `2008-02-15/2008-03-14`
+        match self {
+            Interval::StartEnd { start, end } => write!(f, "{}/{}", start, end),
+            Interval::StartDuration { start, duration } => write!(f, "{}/{}", start, duration),
+            Interval::DurationEnd { duration, end } => write!(f, "{}/{}", duration, end),
+        }
+    }
+}
+
+impl Display for RepeatingInterval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // like `R5/2008-03-01/P1D`, or `R/2008-03-01/P1D` if unbounded
+        write!(f, "R")?;
+        if let Some(repetitions) = self.repetitions {
+            write!(f, "{}", repetitions)?;
+        }
+        write!(f, "/{}", self.interval)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::parsers::parse_duration;
@@ -131,11 +445,12 @@ mod tests {
         let duration = Duration::YMDHMS {
             year: 2021,
             month: 11,
+            week: 0,
             day: 16,
             hour: 23,
             minute: 26,
             second: 59,
-            millisecond: 0,
+            nanosecond: 0,
         };
         assert_duration_reparse(duration);
     }
@@ -145,11 +460,12 @@ mod tests {
         let duration = Duration::YMDHMS {
             year: 2021,
             month: 11,
+            week: 0,
             day: 16,
             hour: 23,
             minute: 26,
             second: 59,
-            millisecond: 123,
+            nanosecond: 123_000_000,
         };
         assert_duration_reparse(duration);
     }
@@ -160,17 +476,43 @@ mod tests {
         assert_duration_reparse(duration);
     }
 
+    #[test]
+    fn duration_negative() {
+        let duration = crate::duration("-P1Y2M3DT4H5M6.5S").unwrap();
+        assert_eq!(format!("{}", duration), "-P1Y2M3DT4H5M6.500S");
+        assert_duration_reparse(duration);
+
+        let duration = crate::duration("PT-0.25S").unwrap();
+        assert_eq!(format!("{}", duration), "-PT0.250S");
+        assert_duration_reparse(duration);
+
+        let duration = crate::duration("P1DT-1H").unwrap();
+        assert_eq!(format!("{}", duration), "P1DT-1H");
+        assert_duration_reparse(duration);
+
+        let duration = crate::duration("PT1M-0.5S").unwrap();
+        assert_eq!(format!("{}", duration), "PT1M-0.500S");
+        assert_duration_reparse(duration);
+
+        let duration = Duration::Weeks(-3);
+        assert_eq!(format!("{}", duration), "-P3W");
+        assert_duration_reparse(duration);
+
+        assert_eq!(format!("{}", crate::duration("-P0D").unwrap()), "P0D");
+    }
+
     #[test]
     fn duration_small_milliseconds() {
         for millisecond in [1, 10, 100] {
             let duration = Duration::YMDHMS {
                 year: 0,
                 month: 0,
+                week: 0,
                 day: 0,
                 hour: 0,
                 minute: 0,
                 second: 0,
-                millisecond,
+                nanosecond: millisecond * 1_000_000,
             };
             assert_duration_reparse(duration);
         }
@@ -179,10 +521,36 @@ mod tests {
     #[test]
     fn time_small_milliseconds() {
         let time = crate::time("16:43:16.001").unwrap();
-        assert_eq!(format!("{}", time), "16:43:16.001+00:00");
+        assert_eq!(format!("{}", time), "16:43:16.001");
 
         let time = crate::time("16:43:16.010").unwrap();
-        assert_eq!(format!("{}", time), "16:43:16.010+00:00");
+        assert_eq!(format!("{}", time), "16:43:16.010");
+    }
+
+    #[test]
+    fn duration_small_nanoseconds() {
+        for nanosecond in [1, 10, 100, 1_000, 123_456, 123_456_789] {
+            let duration = Duration::YMDHMS {
+                year: 0,
+                month: 0,
+                week: 0,
+                day: 0,
+                hour: 0,
+                minute: 0,
+                second: 1,
+                nanosecond,
+            };
+            assert_duration_reparse(duration);
+        }
+    }
+
+    #[test]
+    fn time_sub_millisecond_precision() {
+        let time = crate::time("16:43:16.123456").unwrap();
+        assert_eq!(format!("{}", time), "16:43:16.123456");
+
+        let time = crate::time("16:43:16.000000001").unwrap();
+        assert_eq!(format!("{}", time), "16:43:16.000000001");
     }
 
     fn assert_date_reparse(date: Date) {
@@ -217,6 +585,20 @@ mod tests {
         });
     }
 
+    #[test]
+    fn date_reduced_precision() {
+        assert_date_reparse(Date::YearMonth {
+            year: 2015,
+            month: 11,
+        });
+        assert_date_reparse(Date::YearWeek { year: 2015, ww: 5 });
+        assert_date_reparse(Date::Year { year: 2015 });
+        assert_date_reparse(Date::Century { century: 20 });
+        assert_date_reparse(Date::Century { century: -3 });
+        assert_date_reparse(Date::Century { century: 123 });
+        assert_date_reparse(Date::Century { century: -100 });
+    }
+
     #[test]
     fn date_negative_year() {
         assert_date_reparse(Date::YMD {
@@ -246,9 +628,9 @@ mod tests {
                 hour: 16,
                 minute: 43,
                 second: 16,
-                millisecond: 123,
-                tz_offset_hours: 5,
-                tz_offset_minutes: 30,
+                nanosecond: 123_000_000,
+                offset: Offset::Fixed(UtcOffset::from_hms(5, 30, 0).unwrap()),
+                precision: TimePrecision::Second,
             },
         });
     }
@@ -259,12 +641,53 @@ mod tests {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 0,
-            tz_offset_hours: -5,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            offset: Offset::Fixed(UtcOffset::from_hms(-5, 0, 0).unwrap()),
+            precision: TimePrecision::Second,
         };
         let serialized = format!("{}", time);
         let reparsed = crate::parsers::parse_time(serialized.as_bytes()).unwrap().1;
         assert_eq!(time, reparsed, "serialized as {:?}", serialized);
     }
+
+    fn assert_interval_reparse(interval: Interval) {
+        let serialized = format!("{}", interval);
+        let reparsed = crate::parsers::parse_interval(serialized.as_bytes())
+            .unwrap()
+            .1;
+        assert_eq!(interval, reparsed, "serialized as {:?}", serialized);
+    }
+
+    #[test]
+    fn interval() {
+        for s in [
+            "2007-03-01T13:00:00Z/2008-05-11T15:30:00Z",
+            "2007-03-01T13:00:00Z/P1Y2M10DT2H30M",
+            "P1Y2M10DT2H30M/2008-05-11T15:30:00Z",
+            "2008-02-15/03-14",
+            "2007-12-14T13:30/15:30",
+            "2015-W45-1/P1W",
+            "2004-06/2006-08",
+        ] {
+            assert_interval_reparse(crate::interval(s).unwrap());
+        }
+    }
+
+    #[test]
+    fn interval_abbreviated_end_written_out() {
+        let interval = crate::interval("2008-02-15/03-14").unwrap();
+        assert_eq!(format!("{}", interval), "2008-02-15/2008-03-14");
+    }
+
+    #[test]
+    fn repeating_interval() {
+        for s in [
+            "R5/2008-03-01T13:00:00.000+00:00/P1Y2M10DT2H30M",
+            "R/P1D/2008-03-01",
+            "R0/2008-03-01/2008-03-02",
+        ] {
+            let repeating = crate::repeating_interval(s).unwrap();
+            assert_eq!(format!("{}", repeating), s);
+        }
+    }
 }
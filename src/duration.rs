@@ -1,8 +1,11 @@
-use core::str::FromStr;
+use alloc::string::{String, ToString};
+use core::{
+    fmt::{self, Display},
+    ops::Neg,
+    str::FromStr,
+};
 
-use alloc::string::String;
-
-use crate::parsers;
+use crate::{display::write_fraction, parsers, ParseError};
 
 /// A time duration.
 /// Durations:
@@ -16,49 +19,208 @@ use crate::parsers;
 ///    dur-month         = 1*DIGIT "M" [dur-day]
 ///    dur-year          = 1*DIGIT "Y" [dur-month]
 ///    dur-date          = (dur-day / dur-month / dur-year) [dur-time]
+///
+/// ISO 8601-2 also allows weeks alongside the other units, like `P1Y2W3DT4H`.
 ///    duration          = "P" (dur-date / dur-time / dur-week)
 /// ```
 ///# use std::str::FromStr;
-/// assert_eq!(iso8601::Duration::from_str("P2021Y11M16DT23H26M59.123S"), Ok(iso8601::Duration::YMDHMS{ year: 2021, month: 11, day: 16, hour: 23, minute: 26, second: 59, millisecond: 123 }))
+/// assert_eq!(iso8601::Duration::from_str("P2021Y11M16DT23H26M59.123S"), Ok(iso8601::Duration::YMDHMS{ year: 2021, month: 11, week: 0, day: 16, hour: 23, minute: 26, second: 59, nanosecond: 123_000_000 }))
+/// ```
+///
+/// Each component is signed, a negative duration has negative components:
+/// ```
+///# use std::str::FromStr;
+/// assert_eq!(iso8601::Duration::from_str("-P3DT4H"), iso8601::Duration::from_str("P-3DT-4H"));
 /// ```
 #[derive(Eq, PartialEq, Debug, Copy, Clone)]
 pub enum Duration {
-    /// A duration specified by year, month, day, hour, minute and second units
+    /// A duration specified by year, month, week, day, hour, minute and second units
     YMDHMS {
         /// Number of calendar years
-        year: u32,
+        year: i32,
         /// Number of months
-        month: u32,
+        month: i32,
+        /// Number of weeks, a duration of weeks only like `P1W` parses as a [`Duration::Weeks`]
+        week: i32,
         /// Number of days
-        day: u32,
+        day: i32,
         /// Number of hours
-        hour: u32,
+        hour: i32,
         /// Number of minutes
-        minute: u32,
+        minute: i32,
         /// Number of seconds
-        second: u32,
-        /// Number of milliseconds
-        millisecond: u32,
+        second: i32,
+        /// Number of nanoseconds, with the same sign as the seconds
+        nanosecond: i32,
     },
     /// consists of week units
-    Weeks(u32),
+    Weeks(i32),
 }
 
 impl Duration {
     /// Whether this duration represents a zero duration.
+    ///
+    /// ```
+    /// assert!(iso8601::duration("-PT0S").unwrap().is_zero());
+    /// assert!(!iso8601::duration("P1DT-24H").unwrap().is_zero());
+    /// ```
     pub fn is_zero(&self) -> bool {
         *self
             == Duration::YMDHMS {
                 year: 0,
                 month: 0,
+                week: 0,
                 day: 0,
                 hour: 0,
                 minute: 0,
                 second: 0,
-                millisecond: 0,
+                nanosecond: 0,
             }
             || *self == Duration::Weeks(0)
     }
+
+    /// Formats this duration in the alternative format, like `P0001-02-03T04:05:06`.
+    ///
+    /// Weeks are written as days, and a negative duration gets a leading `-`.
+    /// Returns `None` if the duration can't be represented,
+    /// i.e. if its components have mixed signs,
+    /// or a component exceeds its carry-over point:
+    /// 9999 years, 12 months, 30 days, 24 hours, 60 minutes or 60 seconds,
+    /// and 24 hours come without minutes and seconds.
+    ///
+    /// ```
+    /// let duration = iso8601::duration("P1Y2M3DT4H5M6.5S").unwrap();
+    /// assert_eq!(duration.format_alternative().unwrap(), "P0001-02-03T04:05:06.500");
+    ///
+    /// let duration = iso8601::duration("P13M").unwrap();
+    /// assert_eq!(duration.format_alternative(), None);
+    /// ```
+    pub fn format_alternative(&self) -> Option<String> {
+        let components = match *self {
+            Duration::YMDHMS {
+                year,
+                month,
+                week,
+                day,
+                hour,
+                minute,
+                second,
+                nanosecond,
+            } => {
+                let day = i64::from(week) * 7 + i64::from(day);
+                [
+                    year,
+                    month,
+                    i32::try_from(day).ok()?,
+                    hour,
+                    minute,
+                    second,
+                    nanosecond,
+                ]
+            }
+            Duration::Weeks(week) => [0, 0, week.checked_mul(7)?, 0, 0, 0, 0],
+        };
+        let negative = components.iter().any(|&n| n < 0);
+        if negative && components.iter().any(|&n| n > 0) {
+            return None;
+        }
+
+        let [year, month, day, hour, minute, second, nanosecond] =
+            components.map(i32::unsigned_abs);
+        if year > 9999
+            || month > 12
+            || day > 30
+            || hour > 24
+            || minute > 60
+            || second > 60
+            || nanosecond > 999_999_999
+            || (hour == 24 && (minute, second, nanosecond) != (0, 0, 0))
+        {
+            return None;
+        }
+
+        let formatted = Alternative {
+            negative,
+            date: (year, month, day),
+            time: (hour, minute, second, nanosecond),
+        };
+        Some(formatted.to_string())
+    }
+
+    /// Negates every component, or returns `None` if one of them is `i32::MIN`.
+    ///
+    /// Parsed durations never have such a component, their negation always succeeds.
+    ///
+    /// ```
+    /// let duration = iso8601::duration("P1DT-2H").unwrap();
+    /// assert_eq!(duration.checked_neg(), iso8601::duration("-P1DT-2H").ok());
+    /// ```
+    pub fn checked_neg(&self) -> Option<Duration> {
+        let negated = match *self {
+            Duration::YMDHMS {
+                year,
+                month,
+                week,
+                day,
+                hour,
+                minute,
+                second,
+                nanosecond,
+            } => Duration::YMDHMS {
+                year: year.checked_neg()?,
+                month: month.checked_neg()?,
+                week: week.checked_neg()?,
+                day: day.checked_neg()?,
+                hour: hour.checked_neg()?,
+                minute: minute.checked_neg()?,
+                second: second.checked_neg()?,
+                nanosecond: nanosecond.checked_neg()?,
+            },
+            Duration::Weeks(week) => Duration::Weeks(week.checked_neg()?),
+        };
+        Some(negated)
+    }
+
+    // The length in nanoseconds, taking a year to be 365 days and a month 30 days.
+    pub(crate) fn nominal_nanos(&self) -> i128 {
+        let (seconds, nanosecond) = match *self {
+            Duration::YMDHMS {
+                year,
+                month,
+                week,
+                day,
+                hour,
+                minute,
+                second,
+                nanosecond,
+            } => (
+                i128::from(year) * 365 * 86_400
+                    + i128::from(month) * 30 * 86_400
+                    + i128::from(week) * 7 * 86_400
+                    + i128::from(day) * 86_400
+                    + i128::from(hour) * 3600
+                    + i128::from(minute) * 60
+                    + i128::from(second),
+                nanosecond,
+            ),
+            Duration::Weeks(week) => (i128::from(week) * 7 * 86_400, 0),
+        };
+        seconds * 1_000_000_000 + i128::from(nanosecond)
+    }
+}
+
+impl Neg for Duration {
+    type Output = Duration;
+
+    /// Negates every component, see [`Duration::checked_neg`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a component is `i32::MIN`, whose negation doesn't fit an `i32`.
+    fn neg(self) -> Duration {
+        self.checked_neg()
+            .expect("a component of the duration is i32::MIN")
+    }
 }
 
 impl Default for Duration {
@@ -66,49 +228,78 @@ impl Default for Duration {
         Duration::YMDHMS {
             year: 0,
             month: 0,
+            week: 0,
             day: 0,
             hour: 0,
             minute: 0,
             second: 0,
-            millisecond: 0,
+            nanosecond: 0,
         }
     }
 }
 
 impl FromStr for Duration {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         duration(s)
     }
 }
 
-impl From<Duration> for ::core::time::Duration {
-    fn from(duration: Duration) -> Self {
-        match duration {
-            Duration::YMDHMS {
-                year,
-                month,
-                day,
-                hour,
-                minute,
-                second,
-                millisecond,
-            } => {
-                let secs = u64::from(year) * 365 * 86_400
-                    + u64::from(month) * 30 * 86_400
-                    + u64::from(day) * 86_400
-                    + u64::from(hour) * 3600
-                    + u64::from(minute) * 60
-                    + u64::from(second);
-                let nanos = millisecond * 1_000_000;
-                Self::new(secs, nanos)
-            }
-            Duration::Weeks(week) => {
-                let secs = u64::from(week) * 7 * 86_400;
-                Self::from_secs(secs)
-            }
+struct Alternative {
+    negative: bool,
+    date: (u32, u32, u32),
+    time: (u32, u32, u32, u32),
+}
+
+impl Display for Alternative {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (year, month, day) = self.date;
+        let (hour, minute, second, nanosecond) = self.time;
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(
+            f,
+            "P{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            year, month, day, hour, minute, second
+        )?;
+        if nanosecond > 0 {
+            write_fraction(f, nanosecond)?;
         }
+        Ok(())
+    }
+}
+
+/// The error returned when converting a negative [`Duration`] into a [`core::time::Duration`].
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct NegativeDuration;
+
+impl Display for NegativeDuration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("duration is negative")
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::error::Error for NegativeDuration {}
+
+impl TryFrom<Duration> for ::core::time::Duration {
+    type Error = NegativeDuration;
+
+    /// Converts a duration into seconds, taking a year to be 365 days and a month 30 days.
+    ///
+    /// Components of mixed signs are added up, so this only fails
+    /// if the duration is negative as a whole.
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        let nanos = duration.nominal_nanos();
+        if nanos < 0 {
+            return Err(NegativeDuration);
+        }
+        Ok(Self::new(
+            (nanos / 1_000_000_000) as u64,
+            (nanos % 1_000_000_000) as u32,
+        ))
     }
 }
 
@@ -118,17 +309,23 @@ impl From<Duration> for ::core::time::Duration {
 ///
 /// * Fully-specified duration: `P1Y2M3DT4H5M6S`
 /// * Duration in weekly intervals: `P1W`
-/// * Fully-specified duration in [`DateTime`](`crate::DateTime`) format: `P<datetime>`
+/// * Weeks combined with the other units: `P1W2D` or `P2WT12H`
+/// * Fully-specified duration in the alternative format: `P0001-02-03T04:05:06`
+///   or `P00010203T040506`
 ///
-/// Both fully-specified formats get parsed into the YMDHMS Duration variant.
+/// Both fully-specified formats and weeks combined with other units
+/// get parsed into the YMDHMS Duration variant.
 /// The weekly interval format gets parsed into the Weeks Duration variant.
 ///
+/// A leading `-` negates the whole duration, e.g. `-P3DT4H`,
+/// and each unit of the first two formats may be negated on its own, e.g. `PT-1.5S`.
+///
 /// The ranges for each of the individual units are not expected to exceed
 /// the next largest unit.
 ///
 /// These ranges (inclusive) are as follows:
 ///
-/// * Year (any valid u32)
+/// * Year (any valid i32)
 /// * Month 0 - 12
 /// * Week 0 - 52
 /// * Day 0 - 31
@@ -136,17 +333,22 @@ impl From<Duration> for ::core::time::Duration {
 /// * Minute 0 - 60
 /// * Second 0 - 60
 ///
+/// The alternative format rejects values beyond these ranges,
+/// with four digits for the year and at most 30 days,
+/// and may not mix the basic and extended format or carry a timezone offset.
+///
+/// The lowest-order unit may have a decimal fraction if it is a day, hour,
+/// minute or second, e.g. `PT2.5H` or `P0.5D`. It is spread over the lower units,
+/// taking a day to be 24 hours, so `PT2.5H` is parsed as `PT2H30M`.
+///
 /// ## Examples
 ///
 /// ```rust
 /// let duration = iso8601::duration("P1Y2M3DT4H5M6S").unwrap();
 /// let duration = iso8601::duration("P1W").unwrap();
 /// let duration = iso8601::duration("P2015-11-03T21:56").unwrap();
+/// let duration = iso8601::duration("-P1D").unwrap();
 /// ```
-pub fn duration(string: &str) -> Result<Duration, String> {
-    if let Ok((_left_overs, parsed)) = parsers::parse_duration(string.as_bytes()) {
-        Ok(parsed)
-    } else {
-        Err(format!("Failed to parse duration: {}", string))
-    }
+pub fn duration(string: &str) -> Result<Duration, ParseError> {
+    ParseError::parse_all(string, parsers::parse_duration)
 }
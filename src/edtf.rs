@@ -0,0 +1,412 @@
+//! The [Extended Date/Time Format](https://www.loc.gov/standards/datetime/) (EDTF),
+//! the profile of ISO 8601-2 used by libraries and archives.
+//!
+//! Level 0 consists of dates like `1985-04-12`, `1985-04` or `1985`,
+//! datetimes like `1985-04-12T23:20:30Z` and intervals of dates like `1964/2008`.
+//! Level 1 adds
+//!
+//! * qualifiers for uncertain, approximate or both at the end of a date: `1984?`, `2004-06~`, `2004-06-11%`
+//! * unspecified rightmost digits: `201X`, `20XX`, `2004-XX`, `1985-04-XX`
+//! * seasons in place of the month, `21` to `24` for spring to winter: `2001-21`
+//! * intervals with an open (`..`) or unknown (empty) end: `1985-04-12/..`, `/1985-04-12`
+//! * years with more than four digits, prefixed by a `Y`: `Y170000002`, `Y-170000002`
+//! * negative years: `-1985`
+//!
+//! Level 2 adds
+//!
+//! * qualifiers of single components, before a component for that component only,
+//!   after it for that component and those to its left: `2004-?06-11`, `2004-06~-11`, `?2004-06-~11`
+//! * sets of dates of which one is meant, `[1667,1668,1670..1672]`,
+//!   or all members are, `{1960,1961-12}`, including ranges and open ends: `[..1760-12-03]`, `[1760-12..]`
+//! * years with an exponent, like `Y-17E7` for the year -170000000
+//! * years with significant digits, like `1950S2` for a year of the 1900s, or `Y-17E7S3`
+//!
+//! ```
+//! use iso8601::edtf::{parse_edtf, Edtf, Qualifier};
+//!
+//! let Edtf::Date(date) = parse_edtf("2004-06~").unwrap() else { panic!() };
+//! assert_eq!((date.year, date.month, date.day), (2004, Some(6), None));
+//! assert_eq!(date.qualifier, Some(Qualifier::Approximate));
+//!
+//! assert_eq!(parse_edtf("1985-04-XX/..").unwrap().to_string(), "1985-04-XX/..");
+//! assert!(parse_edtf("2004-13").is_err());
+//! ```
+
+use alloc::vec::Vec;
+use core::{
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use crate::{parsers, DateTime, ParseError};
+
+/// A value of the Extended Date/Time Format.
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub enum Edtf {
+    /// a date, like `1985-04-12`, `2001-21` or `201X?`
+    Date(Date),
+    /// a datetime, like `1985-04-12T23:20:30Z`
+    DateTime(DateTime),
+    /// an interval of dates, like `1984?/2004-06` or `1985-04-12/..`
+    Interval(Interval),
+    /// a set of dates one of which is meant, like `[1667,1668,1670..1672]`
+    OneOf(Vec<SetMember>),
+    /// a set of dates all of which are meant, like `{1960,1961-12}`
+    AllOf(Vec<SetMember>),
+}
+
+/// A member of a set of EDTF dates, see [`Edtf::OneOf`] and [`Edtf::AllOf`].
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum SetMember {
+    /// a date
+    Date(Date),
+    /// the dates from `start` to `end`, like `1670..1672`
+    Range {
+        /// the first date of the range
+        start: Date,
+        /// the last date of the range
+        end: Date,
+    },
+    /// the date and those before it, like `..1760-12-03`, only as the first member
+    Earlier(Date),
+    /// the date and those after it, like `1760-12..`, only as the last member
+    Later(Date),
+}
+
+/// A date of the Extended Date/Time Format, of year, month or day precision.
+///
+/// Unspecified digits are zero in the values and marked in [`Date::unspecified`],
+/// e.g. `201X` has the year `2010` and an unspecified last year digit.
+/// Years with more than four digits are written with a `Y` prefix.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub struct Date {
+    /// the year, negative before year 0
+    pub year: i32,
+    /// whether the year is prefixed by a `Y`, like `Y170000002` or `Y-00000`,
+    /// which years of more than four digits always are
+    pub letter_prefix: bool,
+    /// whether a year of zero is written with a `-`, like `-0000`
+    pub negative_zero: bool,
+    /// the exponent the year is written with, e.g. `7` for `Y17E7`,
+    /// which the year has to be a multiple of `10` to the power of
+    pub exponent: Option<u32>,
+    /// the number of significant digits of the year, e.g. `2` for `1950S2`
+    pub significant_digits: Option<u32>,
+    /// the month, or `21` to `24` for a season, see [`Date::season`]
+    pub month: Option<u32>,
+    /// the day of month, only with a month
+    pub day: Option<u32>,
+    /// the digits that are unspecified, written `X`
+    pub unspecified: Unspecified,
+    /// the qualifier at the end of the date, which applies to the whole date
+    pub qualifier: Option<Qualifier>,
+    /// the qualifiers of single components, see [`Date::year_qualifier`]
+    pub qualifiers: ComponentQualifiers,
+}
+
+impl Date {
+    /// The season in place of the month, if any.
+    ///
+    /// ```
+    /// use iso8601::edtf::{parse_edtf, Edtf, Season};
+    ///
+    /// let Edtf::Date(date) = parse_edtf("2001-21").unwrap() else { panic!() };
+    /// assert_eq!(date.season(), Some(Season::Spring));
+    /// ```
+    pub fn season(&self) -> Option<Season> {
+        match self.month? {
+            21 => Some(Season::Spring),
+            22 => Some(Season::Summer),
+            23 => Some(Season::Autumn),
+            24 => Some(Season::Winter),
+            _ => None,
+        }
+    }
+
+    /// Whether the year is uncertain or approximate,
+    /// considering the qualifiers of the whole date, of the year
+    /// and those following the month or day, which apply to the components on their left.
+    ///
+    /// ```
+    /// use iso8601::edtf::{parse_edtf, Edtf, Qualifier};
+    ///
+    /// let Edtf::Date(date) = parse_edtf("2004-06~-11").unwrap() else { panic!() };
+    /// assert_eq!(date.year_qualifier(), Some(Qualifier::Approximate));
+    /// assert_eq!(date.month_qualifier(), Some(Qualifier::Approximate));
+    /// assert_eq!(date.day_qualifier(), None);
+    /// ```
+    pub fn year_qualifier(&self) -> Option<Qualifier> {
+        let ComponentQualifiers { year, month, day } = self.qualifiers;
+        combined([
+            year.before,
+            year.after,
+            month.after,
+            day.after,
+            self.qualifier,
+        ])
+    }
+
+    /// Whether the month is uncertain or approximate, see [`Date::year_qualifier`].
+    pub fn month_qualifier(&self) -> Option<Qualifier> {
+        let ComponentQualifiers { month, day, .. } = self.qualifiers;
+        self.month?;
+        combined([month.before, month.after, day.after, self.qualifier])
+    }
+
+    /// Whether the day is uncertain or approximate, see [`Date::year_qualifier`].
+    pub fn day_qualifier(&self) -> Option<Qualifier> {
+        let ComponentQualifiers { day, .. } = self.qualifiers;
+        self.day?;
+        combined([day.before, day.after, self.qualifier])
+    }
+}
+
+// Uncertain and approximate add up to both.
+fn combined<const N: usize>(qualifiers: [Option<Qualifier>; N]) -> Option<Qualifier> {
+    let (uncertain, approximate) =
+        qualifiers
+            .iter()
+            .flatten()
+            .fold(
+                (false, false),
+                |(uncertain, approximate), qualifier| match qualifier {
+                    Qualifier::Uncertain => (true, approximate),
+                    Qualifier::Approximate => (uncertain, true),
+                    Qualifier::UncertainApproximate => (true, true),
+                },
+            );
+    match (uncertain, approximate) {
+        (true, true) => Some(Qualifier::UncertainApproximate),
+        (true, false) => Some(Qualifier::Uncertain),
+        (false, true) => Some(Qualifier::Approximate),
+        (false, false) => None,
+    }
+}
+
+/// The unspecified digits of the components of a [`Date`].
+///
+/// Each field is a mask of the digits of its component,
+/// the lowest bit being the last digit, e.g. `0b11` for the year `20XX`.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub struct Unspecified {
+    /// the unspecified digits of the year
+    pub year: u8,
+    /// the unspecified digits of the month
+    pub month: u8,
+    /// the unspecified digits of the day
+    pub day: u8,
+}
+
+/// The qualifiers written next to the components of a [`Date`].
+///
+/// The qualifier after the last component is the one of the whole date, [`Date::qualifier`].
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub struct ComponentQualifiers {
+    /// the qualifiers next to the year
+    pub year: Qualifiers,
+    /// the qualifiers next to the month
+    pub month: Qualifiers,
+    /// the qualifiers next to the day
+    pub day: Qualifiers,
+}
+
+/// The qualifiers before and after a component of a [`Date`], like `?06` or `06~`.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub struct Qualifiers {
+    /// the qualifier before the component, which applies to the component only
+    pub before: Option<Qualifier>,
+    /// the qualifier after the component, which applies to the components on its left as well
+    pub after: Option<Qualifier>,
+}
+
+/// The qualification of a [`Date`].
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum Qualifier {
+    /// `?`, the date is uncertain, possibly not the right one
+    Uncertain,
+    /// `~`, the date is approximate, around the right one
+    Approximate,
+    /// `%`, the date is both uncertain and approximate
+    UncertainApproximate,
+}
+
+/// The seasons an EDTF [`Date`] may have in place of a month.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum Season {
+    /// `21`
+    Spring,
+    /// `22`
+    Summer,
+    /// `23`
+    Autumn,
+    /// `24`
+    Winter,
+}
+
+/// An interval of EDTF dates, at least one end of which is a date.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct Interval {
+    /// the start of the interval
+    pub start: Endpoint,
+    /// the end of the interval
+    pub end: Endpoint,
+}
+
+/// The start or end of an EDTF [`Interval`].
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum Endpoint {
+    /// a date
+    Date(Date),
+    /// `..`, the interval is open at this end
+    Open,
+    /// empty, this end of the interval is unknown
+    Unknown,
+}
+
+impl Display for Edtf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Edtf::Date(date) => write!(f, "{}", date),
+            Edtf::DateTime(datetime) => {
+                // like `1985-04-12T23:20:30Z`
+                let (date, time) = (&datetime.date, &datetime.time);
+                write!(
+                    f,
+                    "{}T{:02}:{:02}:{:02}{}",
+                    date, time.hour, time.minute, time.second, time.offset
+                )
+            }
+            Edtf::Interval(interval) => write!(f, "{}", interval),
+            Edtf::OneOf(members) => write_set(f, members, ("[", "]")),
+            Edtf::AllOf(members) => write_set(f, members, ("{", "}")),
+        }
+    }
+}
+
+fn write_set(
+    f: &mut fmt::Formatter,
+    members: &[SetMember],
+    (open, close): (&str, &str),
+) -> fmt::Result {
+    f.write_str(open)?;
+    for (n, member) in members.iter().enumerate() {
+        if n > 0 {
+            f.write_str(",")?;
+        }
+        match member {
+            SetMember::Date(date) => write!(f, "{}", date)?,
+            SetMember::Range { start, end } => write!(f, "{}..{}", start, end)?,
+            SetMember::Earlier(date) => write!(f, "..{}", date)?,
+            SetMember::Later(date) => write!(f, "{}..", date)?,
+        }
+    }
+    f.write_str(close)
+}
+
+fn write_qualifier(f: &mut fmt::Formatter, qualifier: Option<Qualifier>) -> fmt::Result {
+    match qualifier {
+        Some(qualifier) => write!(f, "{}", qualifier),
+        None => Ok(()),
+    }
+}
+
+// `value` with `width` digits, those of `unspecified` written as `X`.
+fn write_digits(f: &mut fmt::Formatter, value: u32, width: u32, unspecified: u8) -> fmt::Result {
+    for position in (0..width).rev() {
+        if unspecified & (1 << position) != 0 {
+            f.write_str("X")?;
+        } else {
+            write!(f, "{}", value / 10u32.pow(position) % 10)?;
+        }
+    }
+    Ok(())
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ComponentQualifiers { year, month, day } = self.qualifiers;
+
+        write_qualifier(f, year.before)?;
+        let sign = if self.year < 0 || (self.year == 0 && self.negative_zero) {
+            "-"
+        } else {
+            ""
+        };
+        let abs = self.year.unsigned_abs();
+        if let Some(exponent) = self.exponent {
+            // a power of ten beyond any year leaves zero
+            let digits = 10u32.checked_pow(exponent).map_or(0, |power| abs / power);
+            write!(f, "Y{sign}{digits}E{exponent}")?;
+        } else if self.letter_prefix || abs > 9999 {
+            write!(f, "Y{sign}{abs:05}")?;
+        } else {
+            f.write_str(sign)?;
+            write_digits(f, abs, 4, self.unspecified.year)?;
+        }
+        if let Some(significant_digits) = self.significant_digits {
+            write!(f, "S{}", significant_digits)?;
+        }
+        write_qualifier(f, year.after)?;
+
+        if let Some(value) = self.month {
+            f.write_str("-")?;
+            write_qualifier(f, month.before)?;
+            write_digits(f, value, 2, self.unspecified.month)?;
+            write_qualifier(f, month.after)?;
+        }
+        if let Some(value) = self.day {
+            f.write_str("-")?;
+            write_qualifier(f, day.before)?;
+            write_digits(f, value, 2, self.unspecified.day)?;
+            write_qualifier(f, day.after)?;
+        }
+        write_qualifier(f, self.qualifier)
+    }
+}
+
+impl Display for Qualifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Qualifier::Uncertain => "?",
+            Qualifier::Approximate => "~",
+            Qualifier::UncertainApproximate => "%",
+        })
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.start, self.end)
+    }
+}
+
+impl Display for Endpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Endpoint::Date(date) => write!(f, "{}", date),
+            Endpoint::Open => f.write_str(".."),
+            Endpoint::Unknown => Ok(()),
+        }
+    }
+}
+
+impl FromStr for Edtf {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_edtf(s)
+    }
+}
+
+/// Parses a string of the Extended Date/Time Format, up to Level 2.
+///
+/// ## Example
+///
+/// ```rust
+/// let season = iso8601::edtf::parse_edtf("2001-21").unwrap();
+/// let interval = iso8601::edtf::parse_edtf("1984?/2004-06~").unwrap();
+/// let one_of = iso8601::edtf::parse_edtf("[1667,1668,1670..1672]").unwrap();
+/// ```
+pub fn parse_edtf(string: &str) -> Result<Edtf, ParseError> {
+    ParseError::parse_all(string, parsers::parse_edtf)
+}
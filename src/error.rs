@@ -0,0 +1,176 @@
+use core::fmt::{self, Display};
+
+use nom::Parser;
+
+use crate::{parsers, InvalidDate};
+
+/// The component of an ISO 8601 string a parser was working on when it failed.
+#[non_exhaustive]
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum Component {
+    /// the year of a date, or the years of a duration
+    Year,
+    /// the month of a date, or the months of a duration
+    Month,
+    /// the ISO week of a week date, or the weeks of a duration
+    Week,
+    /// the day of a week date
+    WeekDay,
+    /// the day of an ordinal date
+    OrdinalDay,
+    /// the day of month, or the days of a duration
+    Day,
+    /// the hour of a time, or the hours of a duration
+    Hour,
+    /// the minute of a time, or the minutes of a duration
+    Minute,
+    /// the second of a time, or the seconds of a duration
+    Second,
+    /// the decimal fraction following a `.` or `,`
+    Fraction,
+    /// the timezone offset of a time
+    Offset,
+    /// a separator such as the `T` between date and time
+    Separator,
+    /// a designator such as the leading `P` of a duration or a duration unit
+    Designator,
+    /// the number of repetitions of a repeating interval
+    Repetitions,
+}
+
+impl Display for Component {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Component::Year => "year",
+            Component::Month => "month",
+            Component::Week => "week",
+            Component::WeekDay => "day of week",
+            Component::OrdinalDay => "day of year",
+            Component::Day => "day",
+            Component::Hour => "hour",
+            Component::Minute => "minute",
+            Component::Second => "second",
+            Component::Fraction => "fraction",
+            Component::Offset => "timezone offset",
+            Component::Separator => "separator",
+            Component::Designator => "designator",
+            Component::Repetitions => "repetitions",
+        };
+        f.write_str(name)
+    }
+}
+
+/// The reason a parser failed.
+#[non_exhaustive]
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum ErrorKind {
+    /// A character was found where a different one was expected
+    UnexpectedCharacter,
+    /// The input ended before the value was complete
+    UnexpectedEnd,
+    /// A number was well-formed, but outside the range allowed for its component
+    OutOfRange,
+    /// A complete value was parsed, but more input followed it
+    TrailingInput,
+    /// A date was well-formed, but does not exist in the calendar
+    InvalidDate(InvalidDate),
+    /// A separator of the basic format was found in a value of the extended format,
+    /// or the other way around, see [`ParseOptions::consistent_notation`][`crate::ParseOptions::consistent_notation`]
+    InconsistentNotation,
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let description = match self {
+            ErrorKind::UnexpectedCharacter => "unexpected character",
+            ErrorKind::UnexpectedEnd => "unexpected end of input",
+            ErrorKind::OutOfRange => "value out of range",
+            ErrorKind::TrailingInput => "trailing input",
+            ErrorKind::InvalidDate(reason) => return write!(f, "invalid date ({})", reason),
+            ErrorKind::InconsistentNotation => "basic and extended format mixed",
+        };
+        f.write_str(description)
+    }
+}
+
+/// The error returned when parsing a string fails.
+///
+/// ```
+/// use iso8601::{Component, ErrorKind};
+///
+/// let err = iso8601::date("2015-13-01").unwrap_err();
+/// assert_eq!(err.position(), 5);
+/// assert_eq!(err.component(), Some(Component::Month));
+/// assert_eq!(err.kind(), ErrorKind::OutOfRange);
+/// assert_eq!(err.to_string(), "value out of range in month at position 5");
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct ParseError {
+    position: usize,
+    component: Option<Component>,
+    kind: ErrorKind,
+}
+
+impl ParseError {
+    /// Converts the error of a low-level parser into a `ParseError`,
+    /// `input` being the complete input handed to that parser.
+    pub(crate) fn from_nom(input: &[u8], err: nom::Err<parsers::Error<&[u8]>>) -> Self {
+        match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => ParseError {
+                position: input.len() - e.input.len(),
+                component: e.component,
+                kind: e.kind,
+            },
+            nom::Err::Incomplete(_) => ParseError {
+                position: input.len(),
+                component: None,
+                kind: ErrorKind::UnexpectedEnd,
+            },
+        }
+    }
+
+    /// Runs `parser` on `input`, which it has to consume completely.
+    pub(crate) fn parse_all<'a, O>(
+        input: &'a str,
+        mut parser: impl Parser<&'a [u8], Output = O, Error = parsers::Error<&'a [u8]>>,
+    ) -> Result<O, Self> {
+        let input = input.as_bytes();
+        match parser.parse(input) {
+            Ok(([], parsed)) => Ok(parsed),
+            Ok((left_overs, _)) => Err(ParseError {
+                position: input.len() - left_overs.len(),
+                component: None,
+                kind: ErrorKind::TrailingInput,
+            }),
+            Err(err) => Err(Self::from_nom(input, err)),
+        }
+    }
+
+    /// The byte offset into the input at which parsing failed.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The component that failed to parse, if the failure can be attributed to one.
+    pub fn component(&self) -> Option<Component> {
+        self.component
+    }
+
+    /// The reason parsing failed.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+        if let Some(component) = self.component {
+            write!(f, " in {}", component)?;
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::error::Error for ParseError {}
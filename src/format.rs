@@ -0,0 +1,169 @@
+//! The written form of dates, times and datetimes, to display them the way they were parsed.
+//!
+//! [`Display`][`core::fmt::Display`] always writes the extended format,
+//! a fraction of the second in groups of three digits and offsets with minutes,
+//! e.g. `20151102T103000,5+01` is displayed as `2015-11-02T10:30:00.500+01:00`.
+//! The functions here return a [`Format`] along with the parsed value,
+//! which the `display_with` methods of [`Date`], [`Time`] and [`DateTime`] write it in:
+//!
+//! ```
+//! use iso8601::format::parse_datetime;
+//!
+//! let (datetime, format) = parse_datetime("20151102T103000,50+01").unwrap();
+//! assert_eq!(datetime.display_with(format).to_string(), "20151102T103000,50+01");
+//! assert_eq!(datetime.to_string(), "2015-11-02T10:30:00.500+01:00");
+//! ```
+//!
+//! A format records what the values themselves don't: the notation, the digits of the fraction,
+//! the components of the offset and the `T` before a time. The precision of a time is already kept in
+//! [`Time::precision`] and `Z` tells [`Offset::Utc`][`crate::Offset::Utc`] apart from `+00:00`.
+//!
+//! Some details are still normalized:
+//!
+//! * a date or time mixing the basic and extended format, like `2015-1102`, is written in the extended format
+//! * a fraction of an hour or a minute, like `10.5`, is spread into minutes and seconds
+//! * a datetime is written with an uppercase `T`
+
+use crate::{parsers, Date, DateTime, ParseError, Time, TimePrecision};
+
+/// Whether components are written with separators or without.
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub enum Notation {
+    /// without separators, like `20151102` or `1030`
+    Basic,
+    /// with separators, like `2015-11-02` or `10:30`
+    #[default]
+    Extended,
+}
+
+/// How a date, time or datetime is written, beyond its values.
+///
+/// The default is the format of [`Display`][`core::fmt::Display`].
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct Format {
+    /// the notation of the date, like `2015-11-02` or `20151102`
+    pub date: Notation,
+    /// the notation of the time, like `10:30` or `1030`
+    pub time: Notation,
+    /// the number of digits of the fraction of the second, at most 9,
+    /// or `None` for as many as it needs in groups of three
+    pub fraction_digits: Option<u8>,
+    /// whether the fraction follows a `,` instead of a `.`
+    pub decimal_comma: bool,
+    /// the notation of a numeric offset, like `+01:00` or `+0100`
+    pub offset: Notation,
+    /// the lowest-order component of a numeric offset, like `+01` for [`TimePrecision::Hour`]
+    pub offset_precision: TimePrecision,
+    /// whether a time is prefixed by the designator `T`, like `T1030`,
+    /// which a datetime always has between its date and time
+    pub time_designator: bool,
+}
+
+impl Default for Format {
+    fn default() -> Self {
+        Format {
+            date: Notation::Extended,
+            time: Notation::Extended,
+            fraction_digits: None,
+            decimal_comma: false,
+            offset: Notation::Extended,
+            offset_precision: TimePrecision::Minute,
+            time_designator: false,
+        }
+    }
+}
+
+/// Parses a date string, see [`date()`][`crate::date()`], along with its format.
+///
+/// ## Example
+///
+/// ```rust
+/// let (date, format) = iso8601::format::parse_date("2015306").unwrap();
+/// assert_eq!(date.display_with(format).to_string(), "2015306");
+/// ```
+pub fn parse_date(string: &str) -> Result<(Date, Format), ParseError> {
+    let date = ParseError::parse_all(string, parsers::parse_date)?;
+    let mut format = Format::default();
+    read_date(string, &mut format);
+    Ok((date, format))
+}
+
+/// Parses a time string, see [`time()`][`crate::time()`], along with its format.
+///
+/// ## Example
+///
+/// ```rust
+/// let (time, format) = iso8601::format::parse_time("103000,25-0530").unwrap();
+/// assert_eq!(time.display_with(format).to_string(), "103000,25-0530");
+/// let (time, format) = iso8601::format::parse_time("T1030").unwrap();
+/// assert_eq!(time.display_with(format).to_string(), "T1030");
+/// ```
+pub fn parse_time(string: &str) -> Result<(Time, Format), ParseError> {
+    let time = ParseError::parse_all(string, parsers::parse_time)?;
+    let mut format = Format::default();
+    let unprefixed = string.strip_prefix('T');
+    format.time_designator = unprefixed.is_some();
+    read_time(unprefixed.unwrap_or(string), &mut format);
+    Ok((time, format))
+}
+
+/// Parses a datetime string, see [`datetime()`][`crate::datetime()`], along with its format.
+///
+/// ## Example
+///
+/// ```rust
+/// let (datetime, format) = iso8601::format::parse_datetime("20151102T1030Z").unwrap();
+/// assert_eq!(datetime.display_with(format).to_string(), "20151102T1030Z");
+/// ```
+pub fn parse_datetime(string: &str) -> Result<(DateTime, Format), ParseError> {
+    let datetime = ParseError::parse_all(string, parsers::parse_datetime)?;
+    let mut format = Format::default();
+    // a date has no `T`, which separates it from the time
+    if let Some((date, time)) = string.split_once('T') {
+        read_date(date, &mut format);
+        read_time(time, &mut format);
+    }
+    Ok((datetime, format))
+}
+
+// The input has been parsed already, so only the separators are left to look at.
+// Without a position for a separator, like in `10` or `+01`, the notation stays extended.
+
+fn read_date(date: &str, format: &mut Format) {
+    // after the sign of an expanded year
+    if !date.get(1..).unwrap_or_default().contains('-') {
+        format.date = Notation::Basic;
+    }
+}
+
+fn read_time(time: &str, format: &mut Format) {
+    let (time_of_day, offset) = time.split_at(time.find(['Z', '+', '-']).unwrap_or(time.len()));
+
+    let (whole, fraction) = match time_of_day.split_once(['.', ',']) {
+        Some((whole, fraction)) => {
+            format.decimal_comma = time_of_day.contains(',');
+            (whole, fraction)
+        }
+        None => (time_of_day, ""),
+    };
+    let digits = whole.bytes().filter(u8::is_ascii_digit).count();
+    if digits > 2 && !whole.contains(':') {
+        format.time = Notation::Basic;
+    }
+    // a fraction of the hour or minute is spread into seconds, like the default
+    if digits == 6 {
+        format.fraction_digits = Some(fraction.len().min(9) as u8);
+    }
+
+    if let Some(offset) = offset.get(1..).filter(|offset| !offset.is_empty()) {
+        let digits = offset.bytes().filter(u8::is_ascii_digit).count();
+        if digits > 2 && !offset.contains(':') {
+            format.offset = Notation::Basic;
+        }
+        format.offset_precision = match digits {
+            2 => TimePrecision::Hour,
+            4 => TimePrecision::Minute,
+            _ => TimePrecision::Second,
+        };
+    }
+}
@@ -0,0 +1,225 @@
+use core::str::FromStr;
+
+use crate::{parsers, Date, DateTime, Duration, ParseError};
+
+/// The start or end of an [`Interval`], either a date or a datetime.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum IntervalEndpoint {
+    /// a date without time
+    Date(Date),
+    /// a date with time
+    DateTime(DateTime),
+}
+
+impl IntervalEndpoint {
+    /// The date part of this endpoint.
+    pub fn date(&self) -> Date {
+        match self {
+            IntervalEndpoint::Date(date) => *date,
+            IntervalEndpoint::DateTime(datetime) => datetime.date,
+        }
+    }
+}
+
+/// A time interval, can hold the three interval formats
+/// that are bounded by at least one endpoint.
+/// ```
+/// # use std::str::FromStr;
+/// use iso8601::{Date, Interval, IntervalEndpoint};
+///
+/// assert_eq!(
+///     Interval::from_str("2008-02-15/03-14"),
+///     Ok(Interval::StartEnd {
+///         start: IntervalEndpoint::Date(Date::YMD { year: 2008, month: 2, day: 15 }),
+///         end: IntervalEndpoint::Date(Date::YMD { year: 2008, month: 3, day: 14 }),
+///     })
+/// );
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum Interval {
+    /// consists of a start and an end, like `2007-03-01T13:00:00Z/2008-05-11T15:30:00Z`
+    StartEnd {
+        /// the start of the interval
+        start: IntervalEndpoint,
+        /// the end of the interval
+        end: IntervalEndpoint,
+    },
+    /// consists of a start and a duration, like `2007-03-01T13:00:00Z/P1Y2M10DT2H30M`
+    StartDuration {
+        /// the start of the interval
+        start: IntervalEndpoint,
+        /// the duration of the interval
+        duration: Duration,
+    },
+    /// consists of a duration and an end, like `P1Y2M10DT2H30M/2008-05-11T15:30:00Z`
+    DurationEnd {
+        /// the duration of the interval
+        duration: Duration,
+        /// the end of the interval
+        end: IntervalEndpoint,
+    },
+}
+
+/// A recurring time interval, like `R5/2008-03-01T13:00:00Z/P1Y2M10DT2H30M`.
+/// ```
+/// # use std::str::FromStr;
+/// let repeating = iso8601::RepeatingInterval::from_str("R/2008-03-01/P1D").unwrap();
+/// assert_eq!(repeating.repetitions, None);
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct RepeatingInterval {
+    /// the number of repetitions, `None` if unbounded
+    pub repetitions: Option<u32>,
+    /// the repeated interval
+    pub interval: Interval,
+}
+
+impl RepeatingInterval {
+    /// The start of each repetition of the interval.
+    ///
+    /// Every occurrence is computed from the start, a duration is added as many times
+    /// as there are occurrences before it, by years and months first.
+    /// The day is clamped to the length of the month,
+    /// e.g. `R/2015-01-31T10:00:00Z/P1M` repeats on the last day of each month.
+    /// The interval between a start and an end is repeated in exact nanoseconds.
+    ///
+    /// Returns `None` if the start is unknown or not a datetime,
+    /// i.e. if the interval is bounded by a duration and an end,
+    /// if an endpoint is a date without time, or doesn't exist in the calendar.
+    ///
+    /// ```
+    /// let repeating = iso8601::repeating_interval("R3/2015-01-31T10:00:00Z/P1M").unwrap();
+    /// let months: Vec<String> = repeating
+    ///     .occurrences()
+    ///     .unwrap()
+    ///     .map(|occurrence| occurrence.date.to_string())
+    ///     .collect();
+    /// assert_eq!(months, ["2015-01-31", "2015-02-28", "2015-03-31"]);
+    /// ```
+    pub fn occurrences(&self) -> Option<Occurrences> {
+        let (start, step) = match self.interval {
+            Interval::StartDuration {
+                start: IntervalEndpoint::DateTime(start),
+                duration,
+            } => (start, Step::Duration(duration)),
+            Interval::StartEnd {
+                start: IntervalEndpoint::DateTime(start),
+                end: IntervalEndpoint::DateTime(end),
+            } => (
+                start,
+                Step::Nanoseconds(end.timestamp_nanos()? - start.timestamp_nanos()?),
+            ),
+            _ => return None,
+        };
+        // the start has to exist for any occurrence to exist
+        start.date.days()?;
+
+        Some(Occurrences {
+            start,
+            step,
+            index: 0,
+            repetitions: self.repetitions,
+        })
+    }
+}
+
+/// An iterator over the start of each repetition of a [`RepeatingInterval`],
+/// see [`RepeatingInterval::occurrences`].
+// Iterators shouldn't be `Copy`, it's too easy to advance a copy by accident.
+#[allow(missing_copy_implementations)]
+#[derive(Debug, Clone)]
+pub struct Occurrences {
+    start: DateTime,
+    step: Step,
+    index: u32,
+    repetitions: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Step {
+    Duration(Duration),
+    Nanoseconds(i128),
+}
+
+impl Iterator for Occurrences {
+    type Item = DateTime;
+
+    fn next(&mut self) -> Option<DateTime> {
+        if self.repetitions.is_some_and(|n| self.index >= n) {
+            return None;
+        }
+
+        // the start as it is, even a leap second or `24:00`
+        let occurrence = match self.step {
+            _ if self.index == 0 => Some(self.start),
+            Step::Duration(duration) => self.start.add_duration(&duration, self.index),
+            Step::Nanoseconds(nanos) => self.start.add_nanoseconds(nanos * i128::from(self.index)),
+        }?;
+        self.index = self.index.checked_add(1)?;
+        Some(occurrence)
+    }
+}
+
+impl FromStr for Interval {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        interval(s)
+    }
+}
+
+/// Parses a time interval string.
+///
+/// A string consists of two parts separated by a `/`,
+/// each a date or datetime, see [`date()`][`crate::date()`] and [`datetime()`][`crate::datetime()`],
+/// or a duration, see [`duration()`][`crate::duration()`]:
+///
+/// * `<start>/<end>`, like `2007-03-01T13:00:00Z/2008-05-11T15:30:00Z`
+/// * `<start>/<duration>`, like `2007-03-01T13:00:00Z/P1Y2M10DT2H30M`
+/// * `<duration>/<end>`, like `P1Y2M10DT2H30M/2008-05-11T15:30:00Z`
+///
+/// The end may leave out its leading components, which are then taken from the start,
+/// and has the same date representation.
+/// After a start with a time, an end like `1530` is a time rather than a month and day:
+///
+/// * `2008-02-15/03-14` ends on `2008-03-14`
+/// * `2004-06/08` ends in `2004-08`, `2004-W06-1/W08-5` on `2004-W08-5`
+/// * `2007-12-14T13:30/15:30` ends on `2007-12-14T15:30`
+/// * `2007-11-13T09:00/15T17:00` ends on `2007-11-15T17:00`
+///
+/// An abbreviated end without offset has the offset of the start,
+/// e.g. `2007-12-14T13:30Z/15:30` ends on `2007-12-14T15:30Z`.
+/// It can't lie before the start, `2008-02-15T10:00/11` is rejected.
+///
+/// ## Example
+///
+/// ```rust
+/// let interval = iso8601::interval("2007-03-01T13:00:00Z/P1Y2M10DT2H30M").unwrap();
+/// ```
+pub fn interval(string: &str) -> Result<Interval, ParseError> {
+    ParseError::parse_all(string, parsers::parse_interval)
+}
+
+impl FromStr for RepeatingInterval {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        repeating_interval(s)
+    }
+}
+
+/// Parses a recurring time interval string.
+///
+/// A string consists of `R`, the number of repetitions, a `/` and an interval,
+/// see [`interval()`] for the supported formats.
+/// The number of repetitions can be left out for an unbounded number of repetitions.
+///
+/// ## Example
+///
+/// ```rust
+/// let repeating = iso8601::repeating_interval("R5/2008-03-01T13:00:00Z/P1Y2M10DT2H30M").unwrap();
+/// let unbounded = iso8601::repeating_interval("R/P1D/2008-03-01").unwrap();
+/// ```
+pub fn repeating_interval(string: &str) -> Result<RepeatingInterval, ParseError> {
+    ParseError::parse_all(string, parsers::parse_repeating_interval)
+}
@@ -1,10 +1,13 @@
 //! ISO8601 is a parser library for the
 //! [ISO8601](https://en.wikipedia.org/wiki/ISO_8601) format
-//! and partially RFC3339.
+//! and its [RFC3339](https://www.rfc-editor.org/rfc/rfc3339) profile, see [`rfc3339`],
+//! as well as the [Extended Date/Time Format](https://www.loc.gov/standards/datetime/), see [`edtf`].
+//! Values can be written back the way they were parsed, see [`format`][`mod@format`].
 //!
-//! Validity of a given date is not guaranteed, this parser will happily parse
+//! Validity of a given date is not guaranteed by default, this parser will happily parse
 //! `"2015-02-29"` as a valid date,
 //! even though 2015 was no leap year.
+//! Use [`Date::validate`] or parse with [`ParseOptions::validate`] to reject such dates.
 //!
 //! # Example
 //!
@@ -14,6 +17,8 @@
 //! let date = "2015-02-29".parse::<iso8601::Date>().unwrap();
 //! let datetime = "2015-06-26T16:43:23+0200".parse::<iso8601::DateTime>().unwrap();
 //! let duration = "P2021Y11M16DT23H26M59.123S".parse::<iso8601::Duration>().unwrap();
+//! let interval = "2015-06-26T16:43:23Z/P1DT2H".parse::<iso8601::Interval>().unwrap();
+//! let repeating = "R5/2015-06-26T16:43:23Z/P1D".parse::<iso8601::RepeatingInterval>().unwrap();
 //! ```
 
 #![allow(clippy::uninlined_format_args)]
@@ -33,23 +38,40 @@
 #[macro_use]
 extern crate std;
 
-#[macro_use]
 extern crate alloc;
 
+mod calendar;
 mod display;
+pub mod edtf;
+pub mod format;
 pub mod parsers;
+pub mod rfc3339;
+
+mod error;
+pub use error::{Component, ErrorKind, ParseError};
+
+mod options;
+pub use options::ParseOptions;
 
 mod date;
-pub use date::{date, Date};
+pub use date::{date, truncated_date, Date, InvalidDate, TruncatedDate};
 
 mod time;
-pub use time::{time, Time};
+pub use time::{time, Time, TimePrecision};
+
+mod offset;
+pub use offset::{Offset, UtcOffset};
 
 mod datetime;
 pub use datetime::{datetime, DateTime};
 
 mod duration;
-pub use duration::{duration, Duration};
+pub use duration::{duration, Duration, NegativeDuration};
+
+mod interval;
+pub use interval::{
+    interval, repeating_interval, Interval, IntervalEndpoint, Occurrences, RepeatingInterval,
+};
 
 #[cfg(feature = "chrono")]
 mod chrono;
@@ -0,0 +1,125 @@
+use core::str::FromStr;
+
+use crate::{parsers, ParseError};
+
+/// The timezone offset of a [`Time`][`crate::Time`].
+///
+/// ```
+/// use iso8601::{Offset, UtcOffset};
+///
+/// assert_eq!(iso8601::time("10:00").unwrap().offset, Offset::Local);
+/// assert_eq!(iso8601::time("10:00Z").unwrap().offset, Offset::Utc);
+/// assert_eq!(iso8601::time("10:00+00:00").unwrap().offset, Offset::Fixed(UtcOffset::UTC));
+/// assert_eq!(iso8601::time("10:00-00:00").unwrap().offset, Offset::Unknown);
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub enum Offset {
+    /// no offset, a local time whose relation to UTC is not known, like `10:00`
+    #[default]
+    Local,
+    /// UTC, designated by `Z`
+    Utc,
+    /// an explicit offset from UTC, like `+05:30` or `+00:00`
+    Fixed(UtcOffset),
+    /// `-00:00`, a time in UTC whose local offset is unknown, see
+    /// [RFC 3339, section 4.3](https://www.rfc-editor.org/rfc/rfc3339#section-4.3)
+    Unknown,
+}
+
+impl Offset {
+    /// The offset from UTC in seconds, `None` for a local time.
+    ///
+    /// ```
+    /// let time = iso8601::time("10:00-05:30").unwrap();
+    /// assert_eq!(time.offset.utc_offset_seconds(), Some(-19800));
+    /// ```
+    pub fn utc_offset_seconds(&self) -> Option<i32> {
+        match *self {
+            Offset::Local => None,
+            Offset::Utc | Offset::Unknown => Some(0),
+            Offset::Fixed(offset) => Some(offset.total_seconds()),
+        }
+    }
+}
+
+/// An offset from UTC of at most 18 hours in either direction,
+/// like `+05:30` or the local mean time `+00:17:30`.
+///
+/// ```
+/// use iso8601::UtcOffset;
+///
+/// let offset = UtcOffset::from_hms(-5, -30, 0).unwrap();
+/// assert_eq!(offset.total_seconds(), -19800);
+/// assert_eq!(offset.to_string(), "-05:30");
+/// assert_eq!("-05:30".parse(), Ok(offset));
+///
+/// assert_eq!(UtcOffset::from_hms(24, 0, 0), None);
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub struct UtcOffset {
+    seconds: i32,
+}
+
+impl UtcOffset {
+    /// The offset of UTC itself, `+00:00`.
+    pub const UTC: UtcOffset = UtcOffset { seconds: 0 };
+
+    /// The largest offset in either direction in seconds, 18 hours.
+    pub const MAX_SECONDS: i32 = 18 * 3600;
+
+    /// Creates an offset of the given number of seconds east of UTC.
+    ///
+    /// Returns `None` if it is more than 18 hours in either direction.
+    pub const fn from_seconds(seconds: i32) -> Option<UtcOffset> {
+        if seconds < -Self::MAX_SECONDS || seconds > Self::MAX_SECONDS {
+            return None;
+        }
+        Some(UtcOffset { seconds })
+    }
+
+    /// Creates an offset from its hours, minutes and seconds,
+    /// which all have the sign of the offset, e.g. `(-5, -30, 0)` for `-05:30`.
+    ///
+    /// Returns `None` if the components have different signs,
+    /// minutes or seconds are not within `-59..=59`,
+    /// or the offset is more than 18 hours in either direction.
+    pub const fn from_hms(hours: i32, minutes: i32, seconds: i32) -> Option<UtcOffset> {
+        if minutes < -59 || minutes > 59 || seconds < -59 || seconds > 59 {
+            return None;
+        }
+        let negative = hours < 0 || minutes < 0 || seconds < 0;
+        let positive = hours > 0 || minutes > 0 || seconds > 0;
+        if negative && positive || hours < -18 || hours > 18 {
+            return None;
+        }
+        Self::from_seconds(hours * 3600 + minutes * 60 + seconds)
+    }
+
+    /// The offset in seconds east of UTC.
+    pub const fn total_seconds(&self) -> i32 {
+        self.seconds
+    }
+
+    /// The hour part of the offset, with the sign of the offset.
+    pub const fn hours(&self) -> i32 {
+        self.seconds / 3600
+    }
+
+    /// The minute part of the offset, with the sign of the offset.
+    pub const fn minutes(&self) -> i32 {
+        self.seconds / 60 % 60
+    }
+
+    /// The second part of the offset, with the sign of the offset.
+    pub const fn seconds(&self) -> i32 {
+        self.seconds % 60
+    }
+}
+
+impl FromStr for UtcOffset {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ParseError::parse_all(s, parsers::parse_offset)
+    }
+}
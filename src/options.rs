@@ -0,0 +1,175 @@
+use crate::{parsers, Date, DateTime, Duration, Interval, ParseError, RepeatingInterval, Time};
+
+/// Options to customize parsing.
+///
+/// The top-level functions like [`date()`][`crate::date()`] parse with the default options.
+/// The low-level parsers taking options are the `parse_*_with` functions in [`parsers`].
+///
+/// ```
+/// let options = iso8601::ParseOptions::new().validate(true);
+///
+/// assert!(options.date("2016-02-29").is_ok());
+/// assert!(options.date("2015-02-29").is_err());
+/// ```
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub struct ParseOptions {
+    pub(crate) validate: bool,
+    pub(crate) expanded_year_digits: Option<u8>,
+    pub(crate) case_insensitive: bool,
+    pub(crate) space_separator: bool,
+    pub(crate) known_leap_seconds: bool,
+    pub(crate) consistent_notation: bool,
+}
+
+impl ParseOptions {
+    /// The default options, the same the top-level functions use.
+    pub const fn new() -> Self {
+        ParseOptions {
+            validate: false,
+            expanded_year_digits: None,
+            case_insensitive: false,
+            space_separator: false,
+            known_leap_seconds: false,
+            consistent_notation: false,
+        }
+    }
+
+    /// Reject dates that don't exist in the calendar, like `2015-02-29`.
+    ///
+    /// See [`Date::validate`] for the rules that are checked. Off by default.
+    pub const fn validate(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Expect years in the expanded representation,
+    /// with a sign and the mutually agreed number of digits beyond the usual four.
+    ///
+    /// By default years have four digits and an optional sign,
+    /// and years with more than four digits are only accepted
+    /// with a sign and in the extended format, e.g. `+12345-01-01`.
+    /// With this option every year needs a sign and exactly `4 + extra_digits` digits,
+    /// in the basic as well as in the extended format.
+    ///
+    /// ```
+    /// let options = iso8601::ParseOptions::new().expanded_year(2);
+    ///
+    /// let date = options.date("+0123450101").unwrap();
+    /// assert_eq!(date, iso8601::Date::YMD { year: 12345, month: 1, day: 1 });
+    /// assert_eq!(date.display_expanded(2).to_string(), "+012345-01-01");
+    ///
+    /// assert!(options.date("2015-01-01").is_err());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `extra_digits` is greater than 5, such years wouldn't fit an `i32`.
+    pub const fn expanded_year(mut self, extra_digits: u8) -> Self {
+        assert!(
+            extra_digits <= 5,
+            "at most 5 extra year digits are supported"
+        );
+        self.expanded_year_digits = Some(extra_digits);
+        self
+    }
+
+    /// Accept letters in either case, like the `t` in `2015-11-02t10:00:00z`
+    /// or the designators in `p1y2m3dt4h`. Off by default.
+    ///
+    /// ```
+    /// let options = iso8601::ParseOptions::new().case_insensitive(true);
+    ///
+    /// assert!(options.datetime("2015-11-02t10:00:00z").is_ok());
+    /// assert!(options.duration("pt1h30m").is_ok());
+    /// assert!(iso8601::datetime("2015-11-02t10:00:00z").is_err());
+    /// ```
+    pub const fn case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = case_insensitive;
+        self
+    }
+
+    /// Accept a space instead of the `T` between date and time,
+    /// like in `2015-11-02 10:00:00Z`. Off by default.
+    ///
+    /// ```
+    /// let options = iso8601::ParseOptions::new().space_separator(true);
+    ///
+    /// assert!(options.datetime("2015-11-02 10:00:00Z").is_ok());
+    /// assert!(iso8601::datetime("2015-11-02 10:00:00Z").is_err());
+    /// ```
+    pub const fn space_separator(mut self, space_separator: bool) -> Self {
+        self.space_separator = space_separator;
+        self
+    }
+
+    /// Only accept the leap seconds of the published table, like `2016-12-31T23:59:60Z`.
+    ///
+    /// By default a leap second is accepted at the end of any UTC day,
+    /// this additionally checks the date of a datetime. Off by default.
+    ///
+    /// ```
+    /// let options = iso8601::ParseOptions::new().known_leap_seconds(true);
+    ///
+    /// assert!(options.datetime("2016-12-31T23:59:60Z").is_ok());
+    /// assert!(options.datetime("2017-01-01T00:59:60+01:00").is_ok());
+    /// assert!(options.datetime("2017-12-31T23:59:60Z").is_err());
+    /// assert!(iso8601::datetime("2017-12-31T23:59:60Z").is_ok());
+    /// ```
+    pub const fn known_leap_seconds(mut self, known_leap_seconds: bool) -> Self {
+        self.known_leap_seconds = known_leap_seconds;
+        self
+    }
+
+    /// Reject values mixing the basic and the extended format,
+    /// like `2015-1102`, `10:3000` or `2015-11-02T1030`. Off by default.
+    ///
+    /// Separators have to be used either everywhere or nowhere,
+    /// from the date to the minutes of the offset.
+    /// The error points at the first separator that is missing or shouldn't be there,
+    /// with [`ErrorKind::InconsistentNotation`][`crate::ErrorKind::InconsistentNotation`].
+    ///
+    /// ```
+    /// let options = iso8601::ParseOptions::new().consistent_notation(true);
+    ///
+    /// assert!(options.datetime("20151102T1030+0100").is_ok());
+    /// assert!(options.datetime("2015-11-02T10:30+01:00").is_ok());
+    /// let err = options.datetime("2015-11-02T1030").unwrap_err();
+    /// assert_eq!((err.kind(), err.position()), (iso8601::ErrorKind::InconsistentNotation, 13));
+    /// assert!(iso8601::datetime("2015-11-02T1030").is_ok());
+    /// ```
+    pub const fn consistent_notation(mut self, consistent_notation: bool) -> Self {
+        self.consistent_notation = consistent_notation;
+        self
+    }
+
+    /// Parses a date string, see [`date()`][`crate::date()`] for the supported formats.
+    pub fn date(&self, string: &str) -> Result<Date, ParseError> {
+        ParseError::parse_all(string, |i| parsers::parse_date_with(i, self))
+    }
+
+    /// Parses a datetime string, see [`datetime()`][`crate::datetime()`] for the supported formats.
+    pub fn datetime(&self, string: &str) -> Result<DateTime, ParseError> {
+        ParseError::parse_all(string, |i| parsers::parse_datetime_with(i, self))
+    }
+
+    /// Parses a time string, see [`time()`][`crate::time()`] for the supported formats.
+    pub fn time(&self, string: &str) -> Result<Time, ParseError> {
+        ParseError::parse_all(string, |i| parsers::parse_time_with(i, self))
+    }
+
+    /// Parses a duration string, see [`duration()`][`crate::duration()`] for the supported formats.
+    pub fn duration(&self, string: &str) -> Result<Duration, ParseError> {
+        ParseError::parse_all(string, |i| parsers::parse_duration_with(i, self))
+    }
+
+    /// Parses a time interval string, see [`interval()`][`crate::interval()`] for the supported formats.
+    pub fn interval(&self, string: &str) -> Result<Interval, ParseError> {
+        ParseError::parse_all(string, |i| parsers::parse_interval_with(i, self))
+    }
+
+    /// Parses a recurring time interval string,
+    /// see [`repeating_interval()`][`crate::repeating_interval()`] for the supported formats.
+    pub fn repeating_interval(&self, string: &str) -> Result<RepeatingInterval, ParseError> {
+        ParseError::parse_all(string, |i| parsers::parse_repeating_interval_with(i, self))
+    }
+}
@@ -1,9 +1,11 @@
-//! The low-level parsers for date, datetime, duration and time.
+//! The low-level parsers for date, datetime, duration, time and offset,
+//! and for the [`rfc3339`][`crate::rfc3339`] and [`edtf`][`crate::edtf`] profiles.
 //!
 //! The top-level functions [`date()`][`crate::date()`], [`datetime()`][`crate::datetime()`],
 //! [`duration()`][`crate::duration()`] and [`time()`][`crate::time()`]
 //! provide convenient wrappers around the low-level parsers,
-//! but throw away leftover input on success.
+//! but fail with [`ErrorKind::TrailingInput`][`crate::ErrorKind::TrailingInput`]
+//! if any input is left over.
 //!
 //! Using the low-level functions provided here allows to recover leftover input
 //! or to combine these parsers with other parser combinators.
@@ -12,62 +14,246 @@ use core::str;
 
 use nom::{
     branch::alt,
-    bytes::complete::{tag, take_while, take_while_m_n},
-    character::complete::one_of,
-    combinator::{map_res, not, opt},
-    error::Error,
+    bytes::complete::{tag, tag_no_case, take_while},
+    character::complete::{one_of, satisfy},
+    combinator::{not, opt, peek, verify},
+    error::{FromExternalError, ParseError},
     sequence::{preceded, separated_pair, terminated},
-    AsChar, Err, IResult, Parser,
+    AsChar, Err, IResult, Input, Parser,
 };
 
-use crate::{Date, DateTime, Duration, Time};
+use crate::{
+    Component, Date, DateTime, Duration, ErrorKind, Interval, IntervalEndpoint, InvalidDate,
+    Offset, ParseOptions, RepeatingInterval, Time, TimePrecision, TruncatedDate, UtcOffset,
+};
+
+mod edtf;
+pub use edtf::parse_edtf;
 
 #[cfg(test)]
 mod tests;
 
+/// The error type of the low-level parsers.
+///
+/// Unlike [`nom::error::Error`] it records which [`Component`] failed
+/// and why, and when used with [`alt`] keeps the error of the alternative
+/// that got furthest into the input.
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct Error<I> {
+    /// The remaining input at the position of the failure
+    pub input: I,
+    /// The component that failed to parse, if known
+    pub component: Option<Component>,
+    /// The reason of the failure
+    pub kind: ErrorKind,
+}
+
+impl<I: Input> Error<I> {
+    fn new(input: I, kind: ErrorKind) -> Self {
+        Error {
+            input,
+            component: None,
+            kind,
+        }
+    }
+
+    fn unexpected(input: I) -> Self {
+        let kind = if input.input_len() == 0 {
+            ErrorKind::UnexpectedEnd
+        } else {
+            ErrorKind::UnexpectedCharacter
+        };
+        Error::new(input, kind)
+    }
+
+    fn invalid_date(input: I, reason: InvalidDate) -> Self {
+        let component = match reason {
+            InvalidDate::Month => Component::Month,
+            InvalidDate::DayOfMonth | InvalidDate::LeapDay => Component::Day,
+            InvalidDate::DayOfYear => Component::OrdinalDay,
+            InvalidDate::Week => Component::Week,
+            InvalidDate::WeekDay => Component::WeekDay,
+        };
+        Error::new(input, ErrorKind::InvalidDate(reason)).in_component(component)
+    }
+
+    // The innermost component wins, it is the most specific one.
+    fn in_component(mut self, component: Component) -> Self {
+        self.component.get_or_insert(component);
+        self
+    }
+}
+
+impl<I: Input> ParseError<I> for Error<I> {
+    fn from_error_kind(input: I, _kind: nom::error::ErrorKind) -> Self {
+        Error::unexpected(input)
+    }
+
+    fn append(_: I, _: nom::error::ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    // A well-formed number that is out of range is a better explanation than
+    // any alternative tripping over it, otherwise the furthest error wins.
+    fn or(self, other: Self) -> Self {
+        match (self.kind, other.kind) {
+            (ErrorKind::OutOfRange, kind) if kind != ErrorKind::OutOfRange => self,
+            (kind, ErrorKind::OutOfRange) if kind != ErrorKind::OutOfRange => other,
+            _ if other.input.input_len() < self.input.input_len() => other,
+            _ => self,
+        }
+    }
+}
+
+impl<I: Input, E> FromExternalError<I, E> for Error<I> {
+    fn from_external_error(input: I, _kind: nom::error::ErrorKind, _e: E) -> Self {
+        Error::unexpected(input)
+    }
+}
+
+type PResult<'a, O> = IResult<&'a [u8], O, Error<&'a [u8]>>;
+
 // UTILITY
 
-fn take_digits(i: &[u8]) -> IResult<&[u8], u32> {
-    let (i, digits) = take_while(AsChar::is_dec_digit).parse(i)?;
+// Attributes any failure of `parser` that isn't attributed yet to `component`.
+fn component<'a, O>(
+    component: Component,
+    mut parser: impl Parser<&'a [u8], Output = O, Error = Error<&'a [u8]>>,
+) -> impl Parser<&'a [u8], Output = O, Error = Error<&'a [u8]>> {
+    move |i| {
+        parser
+            .parse(i)
+            .map_err(|e| e.map(|e| e.in_component(component)))
+    }
+}
 
-    if digits.is_empty() {
-        return Err(Err::Error(Error::new(i, nom::error::ErrorKind::Eof)));
+fn separator<'a>(
+    s: &'static str,
+) -> impl Parser<&'a [u8], Output = &'a [u8], Error = Error<&'a [u8]>> {
+    component(Component::Separator, tag(s))
+}
+
+// A letter like the `Z` of UTC, in either case if the options allow it.
+fn letter<'a>(
+    s: &'static str,
+    options: &ParseOptions,
+) -> impl Parser<&'a [u8], Output = &'a [u8], Error = Error<&'a [u8]>> {
+    let case_insensitive = options.case_insensitive;
+    move |i| {
+        if case_insensitive {
+            tag_no_case(s).parse(i)
+        } else {
+            tag(s).parse(i)
+        }
     }
+}
 
-    let s = str::from_utf8(digits).expect("Invalid data, expected UTF-8 string");
-    let res = s
-        .parse()
-        .expect("Invalid string, expected ASCII representation of a number");
+fn designator<'a>(
+    s: &'static str,
+    options: &ParseOptions,
+) -> impl Parser<&'a [u8], Output = &'a [u8], Error = Error<&'a [u8]>> {
+    component(Component::Designator, letter(s, options))
+}
 
-    Ok((i, res))
+// The `T` between date and time, or a space if the options allow it.
+fn datetime_separator<'a>(
+    options: &ParseOptions,
+) -> impl Parser<&'a [u8], Output = &'a [u8], Error = Error<&'a [u8]>> {
+    let space_separator = options.space_separator;
+    let mut t = letter("T", options);
+    component(Component::Separator, move |i| match t.parse(i) {
+        Err(Err::Error(_)) if space_separator => tag(" ").parse(i),
+        result => result,
+    })
 }
 
-fn take_n_digits(i: &[u8], n: usize) -> IResult<&[u8], u32> {
-    let (i, digits) = take_while_m_n(n, n, AsChar::is_dec_digit)(i)?;
+// Where a separator of the extended format is, or would be in the basic format.
+#[derive(Clone, Copy)]
+struct Notation<'a> {
+    at: &'a [u8],
+    extended: bool,
+}
 
+// An optional separator, telling the format it was written in.
+fn notation<'a>(
+    s: &'static str,
+) -> impl Parser<&'a [u8], Output = Notation<'a>, Error = Error<&'a [u8]>> {
+    move |i: &'a [u8]| {
+        let (rest, separator) = opt(tag(s)).parse(i)?;
+        let notation = Notation {
+            at: i,
+            extended: separator.is_some(),
+        };
+        Ok((rest, notation))
+    }
+}
+
+// Fails at the first separator that doesn't follow the format of the first one,
+// if the options ask for a consistent notation.
+fn consistent<'a>(
+    notations: &[Option<Notation<'a>>],
+    options: &ParseOptions,
+) -> Result<(), Err<Error<&'a [u8]>>> {
+    let mut notations = notations.iter().flatten();
+    let (Some(first), true) = (notations.next(), options.consistent_notation) else {
+        return Ok(());
+    };
+    match notations.find(|notation| notation.extended != first.extended) {
+        // the value was recognized, no other alternative can do better
+        Some(other) => Err(Err::Failure(
+            Error::new(other.at, ErrorKind::InconsistentNotation)
+                .in_component(Component::Separator),
+        )),
+        None => Ok(()),
+    }
+}
+
+fn parse_number(digits: &[u8]) -> Option<u32> {
     let s = str::from_utf8(digits).expect("Invalid data, expected UTF-8 string");
-    let res = s
-        .parse()
-        .expect("Invalid string, expected ASCII representation of a number");
+    s.parse().ok()
+}
+
+fn take_digits(i: &[u8]) -> PResult<'_, u32> {
+    let (rest, digits) = take_while(AsChar::is_dec_digit).parse(i)?;
+
+    if digits.is_empty() {
+        return Err(Err::Error(Error::unexpected(i)));
+    }
 
-    Ok((i, res))
+    match parse_number(digits) {
+        Some(res) => Ok((rest, res)),
+        None => Err(Err::Error(Error::new(i, ErrorKind::OutOfRange))),
+    }
+}
+
+fn take_n_digits(i: &[u8], n: usize) -> PResult<'_, u32> {
+    let count = i.iter().take(n).take_while(|c| c.is_dec_digit()).count();
+
+    if count < n {
+        return Err(Err::Error(Error::unexpected(&i[count..])));
+    }
+
+    match parse_number(&i[..n]) {
+        Some(res) => Ok((&i[n..], res)),
+        None => Err(Err::Error(Error::new(i, ErrorKind::OutOfRange))),
+    }
 }
 
 fn n_digit_in_range(
     i: &[u8],
     n: usize,
     range: impl core::ops::RangeBounds<u32>,
-) -> IResult<&[u8], u32> {
+) -> PResult<'_, u32> {
     let (new_i, number) = take_n_digits(i, n)?;
 
     if range.contains(&number) {
         Ok((new_i, number))
     } else {
-        Err(Err::Error(Error::new(i, nom::error::ErrorKind::Eof)))
+        Err(Err::Error(Error::new(i, ErrorKind::OutOfRange)))
     }
 }
 
-fn sign(i: &[u8]) -> IResult<&[u8], i32> {
+fn sign(i: &[u8]) -> PResult<'_, i32> {
     alt((tag("-"), tag("+")))
         .map(|s: &[u8]| match s {
             b"-" => -1,
@@ -78,305 +264,1281 @@ fn sign(i: &[u8]) -> IResult<&[u8], i32> {
 
 // DATE
 
-// [+/-]YYYY
-fn date_year(i: &[u8]) -> IResult<&[u8], i32> {
-    // The sign is optional, but defaults to `+`
-    (
-        opt(sign),               // [+/-]
-        |i| take_n_digits(i, 4), // year
-    )
-        .map(|(s, year)| s.unwrap_or(1) * year as i32)
-        .parse(i)
+// [+/-]YYYY, or with the expanded representation +/-YYYYY...
+fn date_year<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, i32> {
+    component(Component::Year, |i| match options.expanded_year_digits {
+        // the sign is mandatory with an agreed number of extra digits
+        Some(extra) => (sign, |i| take_n_digits(i, 4 + usize::from(extra))).parse(i),
+        None => alt((
+            // more than four digits are unambiguous if a `-` follows
+            terminated((sign, expanded_year_digits), peek(tag("-"))),
+            // The sign is optional, but defaults to `+`
+            (opt(sign).map(|s| s.unwrap_or(1)), |i| take_n_digits(i, 4)),
+        ))
+        .parse(i),
+    })
+    .map(|(s, year)| s * year as i32)
+    .parse(i)
+}
+
+// between five and nine digits, which still fit into an `i32`
+fn expanded_year_digits(i: &[u8]) -> PResult<'_, u32> {
+    let count = i.iter().take_while(|c| c.is_dec_digit()).count();
+
+    if (5..=9).contains(&count) {
+        take_n_digits(i, count)
+    } else {
+        Err(Err::Error(Error::unexpected(i)))
+    }
 }
 
 // MM
-fn date_month(i: &[u8]) -> IResult<&[u8], u32> {
-    n_digit_in_range(i, 2, 1..=12)
+fn date_month(i: &[u8]) -> PResult<'_, u32> {
+    component(Component::Month, |i| n_digit_in_range(i, 2, 1..=12)).parse(i)
 }
 
 // DD
-fn date_day(i: &[u8]) -> IResult<&[u8], u32> {
-    n_digit_in_range(i, 2, 1..=31)
+fn date_day(i: &[u8]) -> PResult<'_, u32> {
+    component(Component::Day, |i| n_digit_in_range(i, 2, 1..=31)).parse(i)
 }
 
 // WW
-fn date_week(i: &[u8]) -> IResult<&[u8], u32> {
-    n_digit_in_range(i, 2, 1..=53)
+fn date_week(i: &[u8]) -> PResult<'_, u32> {
+    component(Component::Week, |i| n_digit_in_range(i, 2, 1..=53)).parse(i)
 }
 
-fn date_week_day(i: &[u8]) -> IResult<&[u8], u32> {
-    n_digit_in_range(i, 1, 1..=7)
+fn date_week_day(i: &[u8]) -> PResult<'_, u32> {
+    component(Component::WeekDay, |i| n_digit_in_range(i, 1, 1..=7)).parse(i)
 }
 
 // ordinal DDD
-fn date_ord_day(i: &[u8]) -> IResult<&[u8], u32> {
-    n_digit_in_range(i, 3, 1..=366)
+fn date_ord_day(i: &[u8]) -> PResult<'_, u32> {
+    component(Component::OrdinalDay, |i| n_digit_in_range(i, 3, 1..=366)).parse(i)
 }
 
 // YYYY-MM-DD
-fn date_ymd(i: &[u8]) -> IResult<&[u8], Date> {
-    (
-        date_year,     // YYYY
-        opt(tag("-")), // -
-        date_month,    // MM
-        opt(tag("-")), // -
-        date_day,      //DD
+fn date_ymd<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, Date> {
+    let (rest, (year, first, month, second, day)) = (
+        |i| date_year(i, options), // YYYY
+        notation("-"),             // -
+        date_month,                // MM
+        notation("-"),             // -
+        date_day,                  //DD
     )
-        .map(|(year, _, month, _, day)| Date::YMD { year, month, day })
-        .parse(i)
+        .parse(i)?;
+
+    consistent(&[Some(first), Some(second)], options)?;
+    Ok((rest, Date::YMD { year, month, day }))
 }
 
 // YYYY-DDD
-fn date_ordinal(i: &[u8]) -> IResult<&[u8], Date> {
-    separated_pair(date_year, opt(tag("-")), date_ord_day)
+fn date_ordinal<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, Date> {
+    separated_pair(|i| date_year(i, options), opt(tag("-")), date_ord_day)
         .map(|(year, ddd)| Date::Ordinal { year, ddd })
         .parse(i)
 }
 
 // YYYY-"W"WW-D
-fn date_iso_week(i: &[u8]) -> IResult<&[u8], Date> {
-    (
-        date_year,                 // y
-        (opt(tag("-")), tag("W")), // [-]W
-        date_week,                 // w
-        opt(tag("-")),             // [-]
-        date_week_day,             // d
+fn date_iso_week<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, Date> {
+    let (rest, (year, (first, _), ww, second, d)) = (
+        |i| date_year(i, options),                 // y
+        (notation("-"), designator("W", options)), // [-]W
+        date_week,                                 // w
+        notation("-"),                             // [-]
+        date_week_day,                             // d
     )
-        .map(|(year, _, ww, _, d)| Date::Week { year, ww, d })
+        .parse(i)?;
+
+    consistent(&[Some(first), Some(second)], options)?;
+    Ok((rest, Date::Week { year, ww, d }))
+}
+
+// Reduced precision representations must not be followed by anything
+// that could continue a date: `2015` is a year, but `20151` or `2015-1` are nothing.
+fn reduced<'a, O>(
+    parser: impl Parser<&'a [u8], Output = O, Error = Error<&'a [u8]>>,
+) -> impl Parser<&'a [u8], Output = O, Error = Error<&'a [u8]>> {
+    terminated(
+        parser,
+        not(satisfy(|c| c.is_ascii_alphanumeric() || c == '-')),
+    )
+}
+
+// YYYY-"W"WW
+fn date_year_week<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, Date> {
+    reduced(separated_pair(
+        |i| date_year(i, options),
+        (opt(tag("-")), designator("W", options)),
+        date_week,
+    ))
+    .map(|(year, ww)| Date::YearWeek { year, ww })
+    .parse(i)
+}
+
+// YYYY-MM, there is no basic format as it would be ambiguous with YYMMDD
+fn date_year_month<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, Date> {
+    reduced(separated_pair(
+        |i| date_year(i, options),
+        tag("-"),
+        date_month,
+    ))
+    .map(|(year, month)| Date::YearMonth { year, month })
+    .parse(i)
+}
+
+// YYYY
+fn date_year_only<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, Date> {
+    let year = |i| match options.expanded_year_digits {
+        Some(_) => date_year(i, options),
+        // the end of the input delimits an expanded year as well as a `-` does
+        None => alt((
+            (sign, expanded_year_digits).map(|(s, year)| s * year as i32),
+            |i| date_year(i, options),
+        ))
+        .parse(i),
+    };
+
+    reduced(component(Component::Year, year))
+        .map(|year| Date::Year { year })
         .parse(i)
 }
 
+// [+/-]YY, or +/-YYY for centuries of expanded years
+fn date_century<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, Date> {
+    let century = |i| match options.expanded_year_digits {
+        Some(extra) => (sign, |i| take_n_digits(i, 2 + usize::from(extra))).parse(i),
+        // with four digits or more it would be a year
+        None => alt((
+            (sign, |i| take_n_digits(i, 3)),
+            (opt(sign).map(|s| s.unwrap_or(1)), |i| take_n_digits(i, 2)),
+        ))
+        .parse(i),
+    };
+
+    reduced(component(Component::Year, century))
+        .map(|(s, century)| Date::Century {
+            century: s * century as i32,
+        })
+        .parse(i)
+}
+
+// Checks the date against the calendar if the options ask for it.
+fn validated<'a>(
+    i: &'a [u8],
+    (rest, date): (&'a [u8], Date),
+    options: &ParseOptions,
+) -> PResult<'a, Date> {
+    if options.validate {
+        if let Err(reason) = date.validate() {
+            // the date was recognized, no other alternative can do better
+            return Err(Err::Failure(Error::invalid_date(i, reason)));
+        }
+    }
+    Ok((rest, date))
+}
+
+// a date with all of its components, as required in a datetime
+fn complete_date<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, Date> {
+    let parsed = alt((
+        |i| date_ymd(i, options),
+        |i| date_iso_week(i, options),
+        |i| date_ordinal(i, options),
+    ))
+    .parse(i)?;
+
+    validated(i, parsed, options)
+}
+
 /// Parses a date string.
 ///
 /// See [`date()`][`crate::date()`] for the supported formats.
-pub fn parse_date(i: &[u8]) -> IResult<&[u8], Date> {
-    alt((date_ymd, date_iso_week, date_ordinal)).parse(i)
+pub fn parse_date(i: &[u8]) -> IResult<&[u8], Date, Error<&[u8]>> {
+    parse_date_with(i, &ParseOptions::new())
+}
+
+/// Parses a date string with the given options.
+///
+/// See [`ParseOptions`] for the available options.
+pub fn parse_date_with<'a>(
+    i: &'a [u8],
+    options: &ParseOptions,
+) -> IResult<&'a [u8], Date, Error<&'a [u8]>> {
+    let parsed = alt((
+        |i| complete_date(i, options),
+        |i| date_year_week(i, options),
+        |i| date_year_month(i, options),
+        |i| date_year_only(i, options),
+        |i| date_century(i, options),
+    ))
+    .parse(i)?;
+
+    validated(i, parsed, options)
+}
+
+// YY-MM-DD or YYMMDD
+fn truncated_year_of_century(i: &[u8]) -> PResult<'_, TruncatedDate> {
+    (
+        component(Component::Year, |i| take_n_digits(i, 2)),
+        opt(tag("-")),
+        date_month,
+        opt(tag("-")),
+        date_day,
+    )
+        .map(|(yy, _, month, _, day)| TruncatedDate::YearOfCentury { yy, month, day })
+        .parse(i)
+}
+
+// --MM-DD, --MMDD or --MM
+fn truncated_month_day(i: &[u8]) -> PResult<'_, TruncatedDate> {
+    preceded(
+        tag("--"),
+        alt((
+            separated_pair(date_month, opt(tag("-")), date_day)
+                .map(|(month, day)| TruncatedDate::MonthDay { month, day }),
+            reduced(date_month).map(|month| TruncatedDate::Month { month }),
+        )),
+    )
+    .parse(i)
+}
+
+// ---DD
+fn truncated_day(i: &[u8]) -> PResult<'_, TruncatedDate> {
+    preceded(tag("---"), date_day)
+        .map(|day| TruncatedDate::Day { day })
+        .parse(i)
+}
+
+// -Www-D, -WwwD, -Www or -W-D
+fn truncated_week(i: &[u8]) -> PResult<'_, TruncatedDate> {
+    let options = ParseOptions::new();
+    preceded(
+        (tag("-"), designator("W", &options)),
+        alt((
+            (date_week, opt(tag("-")), date_week_day)
+                .map(|(ww, _, d)| TruncatedDate::WeekDay { ww, d }),
+            reduced(date_week).map(|ww| TruncatedDate::Week { ww }),
+            preceded(tag("-"), date_week_day).map(|d| TruncatedDate::DayOfWeek { d }),
+        )),
+    )
+    .parse(i)
+}
+
+// -DDD
+fn truncated_ordinal(i: &[u8]) -> PResult<'_, TruncatedDate> {
+    preceded(tag("-"), date_ord_day)
+        .map(|ddd| TruncatedDate::Ordinal { ddd })
+        .parse(i)
+}
+
+/// Parses a truncated date string of ISO 8601:2000.
+///
+/// See [`truncated_date()`][`crate::truncated_date()`] for the supported formats.
+pub fn parse_truncated_date(i: &[u8]) -> IResult<&[u8], TruncatedDate, Error<&[u8]>> {
+    alt((
+        truncated_year_of_century,
+        truncated_day,
+        truncated_month_day,
+        truncated_week,
+        truncated_ordinal,
+    ))
+    .parse(i)
 }
 
 // TIME
 
 // HH
-fn time_hour(i: &[u8]) -> IResult<&[u8], u32> {
-    n_digit_in_range(i, 2, 0..=24)
+fn time_hour(i: &[u8]) -> PResult<'_, u32> {
+    component(Component::Hour, |i| n_digit_in_range(i, 2, 0..=24)).parse(i)
 }
 
 // MM
-fn time_minute(i: &[u8]) -> IResult<&[u8], u32> {
-    n_digit_in_range(i, 2, 0..=59)
+fn time_minute(i: &[u8]) -> PResult<'_, u32> {
+    component(Component::Minute, |i| n_digit_in_range(i, 2, 0..=59)).parse(i)
 }
 
 // SS
-fn time_second(i: &[u8]) -> IResult<&[u8], u32> {
-    n_digit_in_range(i, 2, 0..=60)
+fn time_second(i: &[u8]) -> PResult<'_, u32> {
+    component(Component::Second, |i| n_digit_in_range(i, 2, 0..=60)).parse(i)
 }
 
-// Converts the fractional part if-any of a number of seconds to milliseconds
-// truncating towards zero if there are more than three digits.
-// e.g. "" -> 0, "1" -> 100, "12" -> 120, "123" -> 123, "1234" -> 123
-fn fraction_millisecond(i: &[u8]) -> IResult<&[u8], u32> {
-    let (i, mut digits) = take_while(AsChar::is_dec_digit).parse(i)?;
-    let mut l = digits.len();
-    if l > 3 {
-        digits = digits.get(0..3).unwrap();
-    }
-    let mut result = 0;
-    if l > 0 {
-        let digits = str::from_utf8(digits).unwrap(); // This can't panic, `digits` will only include digits.
-        result = digits.parse().unwrap();
-    }
-    while l < 3 {
-        result *= 10;
-        l += 1;
-    }
+// Converts the fractional part if-any of a number of seconds to nanoseconds
+// truncating towards zero if there are more than nine digits.
+// e.g. "" -> 0, "1" -> 100_000_000, "123" -> 123_000_000, "1234567891" -> 123_456_789
+fn fraction_nanosecond(i: &[u8]) -> PResult<'_, u32> {
+    let (i, digits) = take_while(AsChar::is_dec_digit).parse(i)?;
+    let result = digits
+        .iter()
+        .chain(core::iter::repeat(&b'0'))
+        .take(9)
+        .fold(0, |acc, digit| acc * 10 + u32::from(digit - b'0'));
     Ok((i, result))
 }
 
+// (,|.) followed by the fraction of the lowest-order component
+fn decimal_fraction(i: &[u8]) -> PResult<'_, u32> {
+    preceded(
+        one_of(",."),
+        component(Component::Fraction, fraction_nanosecond),
+    )
+    .parse(i)
+}
+
+// Spreads a fraction, in billionths of a unit `unit_seconds` long,
+// over hours, minutes, seconds and nanoseconds.
+// e.g. 500_000_000 of an hour -> (0, 30, 0, 0)
+fn spread_fraction(fraction: u32, unit_seconds: u64) -> (u32, u32, u32, u32) {
+    let nanos = u64::from(fraction) * unit_seconds;
+    let secs = nanos / 1_000_000_000;
+    (
+        (secs / 3600) as u32,
+        (secs / 60 % 60) as u32,
+        (secs % 60) as u32,
+        (nanos % 1_000_000_000) as u32,
+    )
+}
+
+// MM[:SS][.(n*)], or MM.(m*) with a fraction of the minute,
+// along with the notation of the separator of the seconds
+fn time_minute_and_below(
+    i: &[u8],
+) -> PResult<'_, (u32, u32, u32, TimePrecision, Option<Notation<'_>>)> {
+    (
+        time_minute,
+        alt((
+            decimal_fraction.map(|f| {
+                let (_, _, s, ns) = spread_fraction(f, 60);
+                (s, ns, TimePrecision::Second, None)
+            }),
+            (
+                opt((notation(":"), time_second)), // [SS]
+                opt(decimal_fraction),             // [.(n*)]
+            )
+                .map(|(s, ns)| match s {
+                    Some((notation, s)) => {
+                        (s, ns.unwrap_or(0), TimePrecision::Second, Some(notation))
+                    }
+                    None => (0, 0, TimePrecision::Minute, None),
+                }),
+        )),
+    )
+        .map(|(m, (s, ns, precision, notation))| (m, s, ns, precision, notation))
+        .parse(i)
+}
+
 /// Parses a time string.
 ///
 /// See [`time()`][`crate::time()`] for the supported formats.
-// HH:MM:[SS][.(m*)][(Z|+...|-...)]
-pub fn parse_time(i: &[u8]) -> IResult<&[u8], Time> {
-    (
-        time_hour,                                         // HH
-        opt(tag(":")),                                     // :
-        time_minute,                                       // MM
-        opt(preceded(opt(tag(":")), time_second)),         // [SS]
-        opt(preceded(one_of(",."), fraction_millisecond)), // [.(m*)]
-        opt(alt((timezone_hour, timezone_utc))),           // [(Z|+...|-...)]
+pub fn parse_time(i: &[u8]) -> IResult<&[u8], Time, Error<&[u8]>> {
+    parse_time_with(i, &ParseOptions::new())
+}
+
+/// Parses a time string with the given options.
+///
+/// See [`ParseOptions`] for the available options.
+// [T](HH(.(h*)|:MM(.(m*)|:[SS][.(n*)]))|HH)[(Z|+...|-...)]
+pub fn parse_time_with<'a>(
+    i: &'a [u8],
+    options: &ParseOptions,
+) -> IResult<&'a [u8], Time, Error<&'a [u8]>> {
+    preceded(
+        opt(designator("T", options)),
+        alt((|i| time_of_day(i, options), |i| time_hour_only(i, options))),
     )
-        .map(|(h, _, m, s, ms, z)| {
-            let (tz_offset_hours, tz_offset_minutes) = z.unwrap_or((0, 0));
+    .parse(i)
+}
 
-            Time {
-                hour: h,
-                minute: m,
-                second: s.unwrap_or(0),
-                millisecond: ms.unwrap_or(0),
-                tz_offset_hours,
-                tz_offset_minutes,
-            }
-        })
-        .parse(i)
+// HH(.(h*)|:MM(.(m*)|:[SS][.(n*)]))[(Z|+...|-...)], the time of a datetime
+fn time_of_day<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, Time> {
+    notated_time_of_day(i, options).map(|(rest, (time, _))| (rest, time))
 }
 
-fn timezone_hour(i: &[u8]) -> IResult<&[u8], (i32, i32)> {
-    (sign, time_hour, opt(preceded(opt(tag(":")), time_minute)))
-        .map(|(s, h, m)| (s * (h as i32), s * (m.unwrap_or(0) as i32)))
-        .parse(i)
+// The time of day along with the notation of its first separator, if it has any.
+fn notated_time_of_day<'a>(
+    i: &'a [u8],
+    options: &ParseOptions,
+) -> PResult<'a, (Time, Option<Notation<'a>>)> {
+    let (rest, (h, (m, s, ns, precision, minute, second), z)) = (
+        time_hour, // HH
+        alt((
+            // .(h*)
+            decimal_fraction.map(|f| {
+                let (_, m, s, ns) = spread_fraction(f, 3600);
+                (m, s, ns, TimePrecision::Second, None, None)
+            }),
+            // :MM...
+            (notation(":"), time_minute_and_below).map(
+                |(minute, (m, s, ns, precision, second))| {
+                    (m, s, ns, precision, Some(minute), second)
+                },
+            ),
+        )),
+        // [(Z|+...|-...)]
+        opt(alt((
+            numeric_offset.map(|(s, o, notation)| (offset(s, o), notation)),
+            (|i| timezone_utc(i, options)).map(|z| (z, None)),
+        ))),
+    )
+        .parse(i)?;
+
+    let (offset, offset_notation) = z.unwrap_or((Offset::Local, None));
+    let notations = [minute, second, offset_notation];
+    consistent(&notations, options)?;
+
+    let time = Time {
+        hour: h,
+        minute: m,
+        second: s,
+        nanosecond: ns,
+        offset,
+        precision,
+    };
+    let (rest, time) = checked_time(i, (rest, time))?;
+    Ok((rest, (time, notations.into_iter().flatten().next())))
+}
+
+// HH[(Z|+...|-...)], a time of reduced precision
+fn time_hour_only<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, Time> {
+    let parsed = terminated(
+        (
+            time_hour,
+            opt(alt((timezone_hour, |i| timezone_utc(i, options)))),
+        ),
+        // anything continuing the hour belongs to a time of higher precision
+        not(satisfy(|c| c.is_ascii_alphanumeric() || ":.,".contains(c))),
+    )
+    .map(|(hour, z)| Time {
+        hour,
+        offset: z.unwrap_or(Offset::Local),
+        precision: TimePrecision::Hour,
+        ..Time::default()
+    })
+    .parse(i);
+
+    checked_time(i, parsed?)
+}
+
+// Checks the parts of a time that depend on each other.
+fn checked_time<'a>(i: &'a [u8], (rest, time): (&'a [u8], Time)) -> PResult<'a, Time> {
+    // 24:00:00 is the end of the day, nothing comes after it
+    if time.hour == 24 && (time.minute, time.second, time.nanosecond) != (0, 0, 0) {
+        return Err(Err::Error(
+            Error::new(i, ErrorKind::OutOfRange).in_component(Component::Hour),
+        ));
+    }
+    if !leap_second_allowed(&time) {
+        return Err(Err::Error(
+            Error::new(i, ErrorKind::OutOfRange).in_component(Component::Second),
+        ));
+    }
+    Ok((rest, time))
+}
+
+// Leap seconds are inserted at the end of a UTC day, i.e. `23:59:60Z`.
+// The UTC time of a local time is unknown, but it can only be the last minute of an hour.
+fn leap_second_allowed(time: &Time) -> bool {
+    if time.second != 60 {
+        return true;
+    }
+    let seconds_of_day = (time.hour * 3600 + time.minute * 60) as i32;
+    match time.offset.utc_offset_seconds() {
+        Some(offset) => (seconds_of_day - offset).rem_euclid(86_400) == 86_340,
+        None => time.minute == 59,
+    }
+}
+
+// An offset of the sign and its hours, minutes and seconds, within ±18:00
+fn checked_offset(
+    i: &[u8],
+    (s, h, m, sec): (i32, u32, u32, u32),
+) -> Result<UtcOffset, Err<Error<&[u8]>>> {
+    UtcOffset::from_hms(s * h as i32, s * m as i32, s * sec as i32).ok_or_else(|| {
+        // nothing but an offset can start with a sign here
+        Err::Failure(Error::new(i, ErrorKind::OutOfRange).in_component(Component::Offset))
+    })
+}
+
+// `-00:00` being an unknown offset
+fn offset(s: i32, utc_offset: UtcOffset) -> Offset {
+    if s < 0 && utc_offset == UtcOffset::UTC {
+        Offset::Unknown
+    } else {
+        Offset::Fixed(utc_offset)
+    }
+}
+
+// SS of an offset, unlike the seconds of a time without leap seconds
+fn offset_second(i: &[u8]) -> PResult<'_, u32> {
+    component(Component::Second, |i| n_digit_in_range(i, 2, 0..=59)).parse(i)
+}
+
+// (+|-)hh[:mm[:ss]] or (+|-)hh[mm[ss]],
+// along with the notation of the separator of the minutes, if any
+fn numeric_offset(i: &[u8]) -> PResult<'_, (i32, UtcOffset, Option<Notation<'_>>)> {
+    let (after_hour, (s, h)) = component(Component::Offset, (sign, time_hour)).parse(i)?;
+    let (rest, ms) = component(
+        Component::Offset,
+        opt(alt((
+            (
+                preceded(tag(":"), time_minute),
+                opt(preceded(tag(":"), offset_second)),
+            )
+                .map(|ms| (ms, true)),
+            (time_minute, opt(offset_second)).map(|ms| (ms, false)),
+        ))),
+    )
+    .parse(after_hour)?;
+    let (m, sec) = ms.map_or((0, 0), |((m, sec), _)| (m, sec.unwrap_or(0)));
+    let notation = ms.map(|(_, extended)| Notation {
+        at: after_hour,
+        extended,
+    });
+
+    Ok((rest, (s, checked_offset(i, (s, h, m, sec))?, notation)))
 }
 
-fn timezone_utc(input: &[u8]) -> IResult<&[u8], (i32, i32)> {
-    tag("Z").map(|_| (0, 0)).parse(input)
+fn timezone_hour(i: &[u8]) -> PResult<'_, Offset> {
+    numeric_offset.map(|(s, o, _)| offset(s, o)).parse(i)
+}
+
+fn timezone_utc<'a>(input: &'a [u8], options: &ParseOptions) -> PResult<'a, Offset> {
+    component(Component::Offset, letter("Z", options))
+        .map(|_| Offset::Utc)
+        .parse(input)
+}
+
+/// Parses a timezone offset, like `Z`, `+05:30`, `-0800` or `+00:17:30`.
+///
+/// Offsets are limited to 18 hours in either direction,
+/// `Z` and `-00:00` are both parsed as [`UtcOffset::UTC`].
+///
+/// ```
+/// use iso8601::{parsers::parse_offset, UtcOffset};
+///
+/// assert_eq!(parse_offset(b"+05:30"), Ok((&b""[..], UtcOffset::from_hms(5, 30, 0).unwrap())));
+/// assert!(parse_offset(b"+19:00").is_err());
+/// ```
+pub fn parse_offset(i: &[u8]) -> IResult<&[u8], UtcOffset, Error<&[u8]>> {
+    alt((
+        component(Component::Offset, tag("Z")).map(|_| UtcOffset::UTC),
+        numeric_offset.map(|(_, o, _)| o),
+    ))
+    .parse(i)
 }
 
 /// Parses a datetime string.
 ///
 /// See [`datetime()`][`crate::datetime()`] for supported formats.
 // Full ISO8601 datetime
-pub fn parse_datetime(i: &[u8]) -> IResult<&[u8], DateTime> {
-    separated_pair(parse_date, tag("T"), parse_time)
-        .map(|(d, t)| DateTime { date: d, time: t })
-        .parse(i)
+pub fn parse_datetime(i: &[u8]) -> IResult<&[u8], DateTime, Error<&[u8]>> {
+    parse_datetime_with(i, &ParseOptions::new())
+}
+
+/// Parses a datetime string with the given options.
+///
+/// See [`ParseOptions`] for the available options.
+pub fn parse_datetime_with<'a>(
+    i: &'a [u8],
+    options: &ParseOptions,
+) -> IResult<&'a [u8], DateTime, Error<&'a [u8]>> {
+    let (after_date, date) = complete_date(i, options)?;
+    let (time_input, _) = datetime_separator(options).parse(after_date)?;
+    let (rest, (time, time_notation)) = notated_time_of_day(time_input, options)?;
+
+    // a complete date has separators, after the sign of the year, in the extended format only
+    let date_notation = Notation {
+        at: i,
+        extended: i[1..i.len() - after_date.len()].contains(&b'-'),
+    };
+    consistent(&[Some(date_notation), time_notation], options)?;
+
+    let datetime = DateTime { date, time };
+
+    if options.known_leap_seconds && datetime.time.second == 60 && !datetime.is_known_leap_second()
+    {
+        return Err(Err::Error(
+            Error::new(i, ErrorKind::OutOfRange).in_component(Component::Second),
+        ));
+    }
+    Ok((rest, datetime))
+}
+
+// RFC 3339
+
+// date-fullyear "-" date-month "-" date-mday, which has to exist
+fn rfc3339_full_date(i: &[u8]) -> PResult<'_, Date> {
+    let parsed = (
+        component(Component::Year, |i| take_n_digits(i, 4)),
+        separator("-"),
+        date_month,
+        separator("-"),
+        date_day,
+    )
+        .map(|(year, _, month, _, day)| Date::YMD {
+            year: year as i32,
+            month,
+            day,
+        })
+        .parse(i)?;
+
+    validated(i, parsed, &ParseOptions::new().validate(true))
+}
+
+// time-hour, unlike ISO 8601 without 24
+fn rfc3339_hour(i: &[u8]) -> PResult<'_, u32> {
+    component(Component::Hour, |i| n_digit_in_range(i, 2, 0..=23)).parse(i)
+}
+
+// "." 1*DIGIT
+fn rfc3339_secfrac(i: &[u8]) -> PResult<'_, u32> {
+    preceded(
+        tag("."),
+        component(
+            Component::Fraction,
+            preceded(peek(satisfy(|c| c.is_ascii_digit())), fraction_nanosecond),
+        ),
+    )
+    .parse(i)
+}
+
+// "Z" / ("+" / "-") time-hour ":" time-minute
+fn rfc3339_offset(i: &[u8]) -> PResult<'_, Offset> {
+    component(
+        Component::Offset,
+        alt((one_of("Zz").map(|_| Offset::Utc), |i| {
+            let (rest, (s, h, _, m)) =
+                (sign, rfc3339_hour, separator(":"), time_minute).parse(i)?;
+            Ok((rest, offset(s, checked_offset(i, (s, h, m, 0))?)))
+        })),
+    )
+    .parse(i)
+}
+
+/// Parses a datetime string following the RFC 3339 profile.
+///
+/// See [`rfc3339`][`crate::rfc3339`] for the differences to [`parse_datetime`].
+// full-date ("T" / "t" / " ") partial-time time-offset
+pub fn parse_rfc3339(i: &[u8]) -> IResult<&[u8], DateTime, Error<&[u8]>> {
+    let (i, (date, _, hour, _, minute, _)) = (
+        rfc3339_full_date,
+        component(Component::Separator, one_of("Tt ")),
+        rfc3339_hour,
+        separator(":"),
+        time_minute,
+        separator(":"),
+    )
+        .parse(i)?;
+    let (rest, (second, nanosecond, offset)) =
+        (time_second, opt(rfc3339_secfrac), rfc3339_offset).parse(i)?;
+
+    let time = Time {
+        hour,
+        minute,
+        second,
+        nanosecond: nanosecond.unwrap_or(0),
+        offset,
+        precision: TimePrecision::Second,
+    };
+    if !leap_second_allowed(&time) {
+        return Err(Err::Error(
+            Error::new(i, ErrorKind::OutOfRange).in_component(Component::Second),
+        ));
+    }
+
+    Ok((rest, DateTime { date, time }))
 }
 
 // DURATION
 
+// [-]1*DIGIT followed by the designator of its unit
+fn duration_unit<'a>(
+    i: &'a [u8],
+    component: Component,
+    unit: &'static str,
+    options: &ParseOptions,
+) -> PResult<'a, i32> {
+    let (rest, (minus, digits)) = terminated(
+        (opt(tag("-")), take_while(AsChar::is_dec_digit)),
+        designator(unit, options),
+    )
+    .parse(i)?;
+
+    if digits.is_empty() {
+        return Err(Err::Error(Error::unexpected(i).in_component(component)));
+    }
+
+    match parse_number(digits).and_then(|n| i32::try_from(n).ok()) {
+        Some(n) if minus.is_some() => Ok((rest, -n)),
+        Some(n) => Ok((rest, n)),
+        // the unit is certain at this point, no other alternative can succeed
+        None => Err(Err::Failure(
+            Error::new(i, ErrorKind::OutOfRange).in_component(component),
+        )),
+    }
+}
+
 ///    dur-year          = 1*DIGIT "Y" [dur-month]
-fn duration_year(i: &[u8]) -> IResult<&[u8], u32> {
-    terminated(take_digits, tag("Y")).parse(i)
+fn duration_year<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, i32> {
+    duration_unit(i, Component::Year, "Y", options)
 }
 
 ///    dur-month         = 1*DIGIT "M" [dur-day]
-fn duration_month(i: &[u8]) -> IResult<&[u8], u32> {
-    terminated(take_digits, tag("M")).parse(i)
+fn duration_month<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, i32> {
+    duration_unit(i, Component::Month, "M", options)
 }
 
 ///    dur-week          = 1*DIGIT "W"
-fn duration_week(i: &[u8]) -> IResult<&[u8], u32> {
-    terminated(take_digits, tag("W")).parse(i)
+fn duration_week<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, i32> {
+    duration_unit(i, Component::Week, "W", options)
 }
 
 //    dur-day           = 1*DIGIT "D"
-fn duration_day(i: &[u8]) -> IResult<&[u8], u32> {
-    terminated(take_digits, tag("D")).parse(i)
+fn duration_day<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, (i32, Option<i32>)> {
+    duration_unit_with_fraction(i, Component::Day, "D", options)
 }
 
 ///    dur-hour          = 1*DIGIT "H" [dur-minute]
 ///    dur-time          = "T" (dur-hour / dur-minute / dur-second)
-fn duration_hour(i: &[u8]) -> IResult<&[u8], u32> {
-    terminated(take_digits, tag("H")).parse(i)
+fn duration_hour<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, (i32, Option<i32>)> {
+    duration_unit_with_fraction(i, Component::Hour, "H", options)
 }
 
 ///    dur-minute        = 1*DIGIT "M" [dur-second]
-fn duration_minute(i: &[u8]) -> IResult<&[u8], u32> {
-    terminated(take_digits, tag("M")).parse(i)
+fn duration_minute<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, (i32, Option<i32>)> {
+    duration_unit_with_fraction(i, Component::Minute, "M", options)
 }
 
-///    dur-second        = 1*DIGIT "S"
-fn duration_second(i: &[u8]) -> IResult<&[u8], u32> {
-    terminated(take_digits, tag("S")).parse(i)
+///    dur-second-ext    = 1*DIGIT (,|.) 1*DIGIT "S"
+fn duration_second_and_nanosecond<'a>(
+    i: &'a [u8],
+    options: &ParseOptions,
+) -> PResult<'a, (i32, i32)> {
+    (|i| duration_unit_with_fraction(i, Component::Second, "S", options))
+        .map(|(s, ns)| (s, ns.unwrap_or(0)))
+        .parse(i)
 }
 
-///    dur-second-ext    = 1*DIGIT (,|.) 1*DIGIT "S"
-fn duration_second_and_millisecond(i: &[u8]) -> IResult<&[u8], (u32, u32)> {
+// [-]1*DIGIT [(,|.) 1*DIGIT] followed by the designator of its unit,
+// the fraction in billionths of the unit, with the sign of the unit
+fn duration_unit_with_fraction<'a>(
+    i: &'a [u8],
+    component: Component,
+    unit: &'static str,
+    options: &ParseOptions,
+) -> PResult<'a, (i32, Option<i32>)> {
     alt((
-        // no milliseconds
-        duration_second.map(|m| (m, 0)),
-        terminated(
-            // with milliseconds
-            separated_pair(take_digits, one_of(",."), fraction_millisecond),
-            tag("S"),
-        ),
+        (|i| duration_unit(i, component, unit, options)).map(|n| (n, None)),
+        |i| {
+            let (rest, (minus, n, fraction)) = terminated(
+                (
+                    opt(tag("-")),
+                    self::component(component, take_digits),
+                    decimal_fraction,
+                ),
+                designator(unit, options),
+            )
+            .parse(i)?;
+
+            let sign = if minus.is_some() { -1 } else { 1 };
+            match i32::try_from(n) {
+                Ok(n) => Ok((rest, (sign * n, Some(sign * fraction as i32)))),
+                Err(_) => Err(Err::Failure(
+                    Error::new(i, ErrorKind::OutOfRange).in_component(component),
+                )),
+            }
+        },
     ))
     .parse(i)
 }
 
-fn duration_time(i: &[u8]) -> IResult<&[u8], (u32, u32, u32, u32)> {
+// `spread_fraction` for a signed fraction, every part gets its sign
+fn spread_signed_fraction(fraction: i32, unit_seconds: u64) -> (i32, i32, i32, i32) {
+    let sign = fraction.signum();
+    let (h, m, s, ns) = spread_fraction(fraction.unsigned_abs(), unit_seconds);
     (
-        opt(duration_hour),
-        opt(duration_minute),
-        opt(duration_second_and_millisecond),
+        sign * h as i32,
+        sign * m as i32,
+        sign * s as i32,
+        sign * ns as i32,
     )
-        .map(|(h, m, s)| {
-            let (s, ms) = s.unwrap_or((0, 0));
+}
 
-            (h.unwrap_or(0), m.unwrap_or(0), s, ms)
-        })
-        .parse(i)
+// Only the lowest-order component may have a fraction,
+// nothing is parsed after it.
+fn duration_time<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, (i32, i32, i32, i32)> {
+    let (i, h) = opt(|i| duration_hour(i, options)).parse(i)?;
+    if let Some((h, Some(f))) = h {
+        let (_, m, s, ns) = spread_signed_fraction(f, 3600);
+        return Ok((i, (h, m, s, ns)));
+    }
+
+    let (i, m) = opt(|i| duration_minute(i, options)).parse(i)?;
+    if let Some((m, Some(f))) = m {
+        let (_, _, s, ns) = spread_signed_fraction(f, 60);
+        return Ok((i, (h.map_or(0, |h| h.0), m, s, ns)));
+    }
+
+    let (i, s) = opt(|i| duration_second_and_nanosecond(i, options)).parse(i)?;
+    let (s, ns) = s.unwrap_or((0, 0));
+    Ok((i, (h.map_or(0, |h| h.0), m.map_or(0, |m| m.0), s, ns)))
 }
 
-fn duration_ymdhms(i: &[u8]) -> IResult<&[u8], Duration> {
-    map_res(
-        preceded(
-            tag("P"),
-            (
-                opt(duration_year),
-                opt(duration_month),
-                opt(duration_day),
-                opt(preceded(tag("T"), duration_time)),
-            ),
-        ),
-        |(y, mo, d, time)| {
-            // at least one element must be present for a valid duration representation
-            if y.is_none() && mo.is_none() && d.is_none() && time.is_none() {
-                return Err((i, nom::error::ErrorKind::Eof));
-            }
+fn duration_ymdhms<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, Duration> {
+    let (i, _) = designator("P", options).parse(i)?;
+    let (rest, (y, mo, w, d)) = (
+        opt(|i| duration_year(i, options)),
+        opt(|i| duration_month(i, options)),
+        opt(|i| duration_week(i, options)),
+        opt(|i| duration_day(i, options)),
+    )
+        .parse(i)?;
 
-            let (h, mi, s, ms) = time.unwrap_or((0, 0, 0, 0));
+    let (rest, time) = match d {
+        // a day of 24 hours, nothing may follow its fraction
+        Some((_, Some(f))) => {
+            let (h, m, s, ns) = spread_signed_fraction(f, 86_400);
+            (rest, Some((h, m, s, ns)))
+        }
+        _ => opt(preceded(designator("T", options), |i| {
+            duration_time(i, options)
+        }))
+        .parse(rest)?,
+    };
 
-            Ok(Duration::YMDHMS {
-                year: y.unwrap_or(0),
-                month: mo.unwrap_or(0),
-                day: d.unwrap_or(0),
-                hour: h,
-                minute: mi,
-                second: s,
-                millisecond: ms,
-            })
+    // at least one element must be present for a valid duration representation,
+    // weeks alone are left to `duration_weeks`
+    if y.is_none() && mo.is_none() && d.is_none() && time.is_none() {
+        return Err(Err::Error(Error::unexpected(i)));
+    }
+
+    let (h, mi, s, ns) = time.unwrap_or((0, 0, 0, 0));
+
+    Ok((
+        rest,
+        Duration::YMDHMS {
+            year: y.unwrap_or(0),
+            month: mo.unwrap_or(0),
+            week: w.unwrap_or(0),
+            day: d.map_or(0, |d| d.0),
+            hour: h,
+            minute: mi,
+            second: s,
+            nanosecond: ns,
         },
-    )
-    .parse(i)
+    ))
 }
 
-fn duration_weeks(i: &[u8]) -> IResult<&[u8], Duration> {
-    preceded(tag("P"), duration_week)
+fn duration_weeks<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, Duration> {
+    preceded(designator("P", options), |i| duration_week(i, options))
         .map(Duration::Weeks)
         .parse(i)
 }
 
 // YYYY, no sign
-fn duration_datetime_year(i: &[u8]) -> IResult<&[u8], u32> {
-    take_n_digits(i, 4)
+fn duration_datetime_year(i: &[u8]) -> PResult<'_, u32> {
+    component(Component::Year, |i| take_n_digits(i, 4)).parse(i)
 }
 
-fn duration_datetime(i: &[u8]) -> IResult<&[u8], Duration> {
+// Two digits of a component of the alternative format,
+// which can't exceed its carry-over point, e.g. 12 months or 30 days.
+fn duration_datetime_component<'a>(
+    component: Component,
+    max: u32,
+) -> impl Parser<&'a [u8], Output = u32, Error = Error<&'a [u8]>> {
+    self::component(component, move |i| n_digit_in_range(i, 2, 0..=max))
+}
+
+// hh[.(h*)|:mm[.(m*)|:ss[.(s*)]]], or without the `:` in the basic format
+fn duration_datetime_time<'a>(
+    i: &'a [u8],
+    separator: &'static str,
+) -> PResult<'a, (u32, u32, u32, u32)> {
+    let (rest, (h, m, s, ns)) = duration_datetime_hms(i, separator)?;
+
+    // 24 hours carry over like 24:00:00 ends a day, nothing comes after them
+    if h == 24 && (m, s, ns) != (0, 0, 0) {
+        return Err(Err::Error(
+            Error::new(i, ErrorKind::OutOfRange).in_component(Component::Hour),
+        ));
+    }
+    Ok((rest, (h, m, s, ns)))
+}
+
+fn duration_datetime_hms<'a>(
+    i: &'a [u8],
+    separator: &'static str,
+) -> PResult<'a, (u32, u32, u32, u32)> {
+    let (i, h) = duration_datetime_component(Component::Hour, 24).parse(i)?;
+    if let Ok((i, f)) = decimal_fraction(i) {
+        let (_, m, s, ns) = spread_fraction(f, 3600);
+        return Ok((i, (h, m, s, ns)));
+    }
+
+    let (i, m) = duration_datetime_next(i, separator, Component::Minute, 60)?;
+    let Some(m) = m else {
+        return Ok((i, (h, 0, 0, 0)));
+    };
+    if let Ok((i, f)) = decimal_fraction(i) {
+        let (_, _, s, ns) = spread_fraction(f, 60);
+        return Ok((i, (h, m, s, ns)));
+    }
+
+    let (i, s) = duration_datetime_next(i, separator, Component::Second, 60)?;
+    let (i, ns) = opt(decimal_fraction).parse(i)?;
+    Ok((i, (h, m, s.unwrap_or(0), ns.unwrap_or(0))))
+}
+
+// The next, optional component of the time of the alternative format,
+// it is there if a digit follows the separator
+fn duration_datetime_next<'a>(
+    i: &'a [u8],
+    separator: &'static str,
+    component: Component,
+    max: u32,
+) -> PResult<'a, Option<u32>> {
+    match i.strip_prefix(separator.as_bytes()) {
+        Some(rest) if rest.first().is_some_and(u8::is_ascii_digit) => {
+            duration_datetime_component(component, max)
+                .map(Some)
+                .parse(rest)
+        }
+        _ => Ok((i, None)),
+    }
+}
+
+// YYYY-MM-DDThh:mm:ss in the extended format, YYYYMMDDThhmmss in the basic format
+fn duration_datetime_with_separators<'a>(
+    i: &'a [u8],
+    options: &ParseOptions,
+    (date_separator, time_separator): (&'static str, &'static str),
+) -> PResult<'a, Duration> {
+    (
+        duration_datetime_year,
+        tag(date_separator),
+        duration_datetime_component(Component::Month, 12),
+        tag(date_separator),
+        duration_datetime_component(Component::Day, 30),
+        datetime_separator(options),
+        |i| duration_datetime_time(i, time_separator),
+    )
+        // every component is bounded to a few digits
+        .map(
+            |(year, _, month, _, day, _, (h, m, s, ns))| Duration::YMDHMS {
+                year: year as i32,
+                month: month as i32,
+                week: 0,
+                day: day as i32,
+                hour: h as i32,
+                minute: m as i32,
+                second: s as i32,
+                nanosecond: ns as i32,
+            },
+        )
+        .parse(i)
+}
+
+// The alternative format, basic and extended can't be mixed
+fn duration_datetime<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, Duration> {
     preceded(
-        (tag("P"), not(sign)),
-        (
-            duration_datetime_year,
-            opt(tag("-")),
-            date_month,
-            opt(tag("-")),
-            date_day,
-            tag("T"),
-            parse_time,
-        ),
+        (designator("P", options), not(sign)),
+        alt((
+            |i| duration_datetime_with_separators(i, options, ("-", ":")),
+            |i| duration_datetime_with_separators(i, options, ("", "")),
+        )),
     )
-    .map(|(year, _, month, _, day, _, t)| Duration::YMDHMS {
-        year,
-        month,
-        day,
-        hour: t.hour,
-        minute: t.minute,
-        second: t.second,
-        millisecond: t.millisecond,
-    })
     .parse(i)
 }
 
 /// Parses a duration string.
 ///
 /// See [`duration()`][`crate::duration()`] for supported formats.
-pub fn parse_duration(i: &[u8]) -> IResult<&[u8], Duration> {
-    alt((duration_ymdhms, duration_weeks, duration_datetime)).parse(i)
+pub fn parse_duration(i: &[u8]) -> IResult<&[u8], Duration, Error<&[u8]>> {
+    parse_duration_with(i, &ParseOptions::new())
+}
+
+/// Parses a duration string with the given options.
+///
+/// See [`ParseOptions`] for the available options.
+// [-]P...
+pub fn parse_duration_with<'a>(
+    i: &'a [u8],
+    options: &ParseOptions,
+) -> IResult<&'a [u8], Duration, Error<&'a [u8]>> {
+    (
+        opt(tag("-")),
+        alt((
+            |i| duration_ymdhms(i, options),
+            |i| duration_weeks(i, options),
+            |i| duration_datetime(i, options),
+        )),
+    )
+        .map(|(minus, duration)| if minus.is_some() { -duration } else { duration })
+        .parse(i)
+}
+
+// INTERVAL
+
+fn interval_endpoint<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, IntervalEndpoint> {
+    alt((
+        (|i| parse_datetime_with(i, options)).map(IntervalEndpoint::DateTime),
+        (|i| parse_date_with(i, options)).map(IntervalEndpoint::Date),
+    ))
+    .parse(i)
+}
+
+// [MM[-]]DD, the leading components of a calendar date left out
+fn abbreviated_month_day(i: &[u8]) -> PResult<'_, (Option<u32>, u32)> {
+    alt((
+        (date_month, opt(tag("-")), date_day).map(|(m, _, d)| (Some(m), d)),
+        date_day.map(|d| (None, d)),
+    ))
+    .parse(i)
+}
+
+// The trailing components of a date in the representation of `start`,
+// completed with its leading components:
+// [MM[-]]DD, MM, "W"WW[-]D, "W"WW or DDD
+fn abbreviated_date<'a>(
+    i: &'a [u8],
+    start: &IntervalEndpoint,
+    options: &ParseOptions,
+) -> PResult<'a, Date> {
+    match start.date() {
+        Date::YMD { year, month, .. } => abbreviated_month_day
+            .map(|(m, day)| Date::YMD {
+                year,
+                month: m.unwrap_or(month),
+                day,
+            })
+            .parse(i),
+        Date::YearMonth { year, .. } => date_month
+            .map(|month| Date::YearMonth { year, month })
+            .parse(i),
+        Date::Week { year, .. } => (
+            designator("W", options),
+            date_week,
+            opt(tag("-")),
+            date_week_day,
+        )
+            .map(|(_, ww, _, d)| Date::Week { year, ww, d })
+            .parse(i),
+        Date::YearWeek { year, .. } => preceded(designator("W", options), date_week)
+            .map(|ww| Date::YearWeek { year, ww })
+            .parse(i),
+        Date::Ordinal { year, .. } => date_ord_day.map(|ddd| Date::Ordinal { year, ddd }).parse(i),
+        Date::Year { .. } | Date::Century { .. } => Err(Err::Error(Error::unexpected(i))),
+    }
+}
+
+// The time of an end, which has the offset of `start` if it leaves out its own
+fn end_time<'a>(
+    i: &'a [u8],
+    start: &IntervalEndpoint,
+    options: &ParseOptions,
+) -> PResult<'a, Time> {
+    let (rest, time) = time_of_day(i, options)?;
+    match (time.offset, start) {
+        (Offset::Local, IntervalEndpoint::DateTime(start)) => Ok((
+            rest,
+            Time {
+                offset: start.time.offset,
+                ..time
+            },
+        )),
+        _ => Ok((rest, time)),
+    }
+}
+
+// A time completed to a datetime with the date of `start`
+fn abbreviated_datetime<'a>(
+    i: &'a [u8],
+    start: &IntervalEndpoint,
+    options: &ParseOptions,
+) -> PResult<'a, DateTime> {
+    let IntervalEndpoint::DateTime(start_datetime) = start else {
+        return Err(Err::Error(Error::unexpected(i)));
+    };
+    (|i| end_time(i, start, options))
+        .map(|time| DateTime {
+            date: start_datetime.date,
+            time,
+        })
+        .parse(i)
+}
+
+// Requires `parser` to consume a whole endpoint, an abbreviated end like `15`
+// must not be the start of `15:30` or `1530`.
+fn whole_endpoint<'a, O>(
+    parser: impl Parser<&'a [u8], Output = O, Error = Error<&'a [u8]>>,
+) -> impl Parser<&'a [u8], Output = O, Error = Error<&'a [u8]>> {
+    terminated(
+        parser,
+        not(satisfy(|c| {
+            c.is_ascii_alphanumeric() || "-+:.,".contains(c)
+        })),
+    )
+}
+
+// An end leaving out the leading date components of `start`
+fn abbreviated_date_end<'a>(
+    i: &'a [u8],
+    start: &IntervalEndpoint,
+    options: &ParseOptions,
+) -> PResult<'a, IntervalEndpoint> {
+    let (rest, end) = whole_endpoint(alt((
+        // [MM[-]]DDThh:mm..., of a complete date only
+        separated_pair(
+            verify(
+                |i| abbreviated_date(i, start, options),
+                |date| {
+                    matches!(
+                        date,
+                        Date::YMD { .. } | Date::Week { .. } | Date::Ordinal { .. }
+                    )
+                },
+            ),
+            datetime_separator(options),
+            |i| end_time(i, start, options),
+        )
+        .map(|(date, time)| IntervalEndpoint::DateTime(DateTime { date, time })),
+        // [MM[-]]DD
+        (|i| abbreviated_date(i, start, options)).map(IntervalEndpoint::Date),
+    )))
+    .parse(i)?;
+
+    let (rest, _) = validated(i, (rest, end.date()), options)?;
+    Ok((rest, end))
+}
+
+// Whether `end` lies before `start`, with the same year as an abbreviated end has.
+fn ends_before(start: &IntervalEndpoint, end: &IntervalEndpoint) -> bool {
+    if let (IntervalEndpoint::DateTime(start), IntervalEndpoint::DateTime(end)) = (start, end) {
+        return matches!((start.timestamp_nanos(), end.timestamp_nanos()), (Some(s), Some(e)) if e < s);
+    }
+    match (start.date(), end.date()) {
+        (Date::YearMonth { month: s, .. }, Date::YearMonth { month: e, .. })
+        | (Date::YearWeek { ww: s, .. }, Date::YearWeek { ww: e, .. }) => e < s,
+        (start, end) => matches!((start.days(), end.days()), (Some(s), Some(e)) if e < s),
+    }
+}
+
+// An end leaving out the leading components of `start`, which it can't lie before
+fn abbreviated_end<'a>(
+    i: &'a [u8],
+    start: &IntervalEndpoint,
+    options: &ParseOptions,
+) -> PResult<'a, IntervalEndpoint> {
+    let (rest, end) = alt((
+        // hh:mm..., before an abbreviated date as `1130` after a start with a time is 11:30
+        whole_endpoint(|i| abbreviated_datetime(i, start, options)).map(IntervalEndpoint::DateTime),
+        |i| abbreviated_date_end(i, start, options),
+    ))
+    .parse(i)?;
+
+    if ends_before(start, &end) {
+        // the end was recognized, `11` after `2008-02-15` is no century either
+        return Err(Err::Failure(Error::new(i, ErrorKind::OutOfRange)));
+    }
+    Ok((rest, end))
+}
+
+// The end of an interval, complete or abbreviated
+fn interval_end<'a>(
+    i: &'a [u8],
+    start: &IntervalEndpoint,
+    options: &ParseOptions,
+) -> PResult<'a, IntervalEndpoint> {
+    alt((
+        (|i| parse_datetime_with(i, options)).map(IntervalEndpoint::DateTime),
+        (|i| complete_date(i, options)).map(IntervalEndpoint::Date),
+        // `2008-03` is rather a month than 20:08 at an offset of -03:00
+        whole_endpoint(verify(
+            |i| parse_date_with(i, options),
+            |date| matches!(date, Date::YearMonth { .. } | Date::YearWeek { .. }),
+        ))
+        .map(IntervalEndpoint::Date),
+        |i| abbreviated_end(i, start, options),
+        whole_endpoint(|i| parse_date_with(i, options)).map(IntervalEndpoint::Date),
+    ))
+    .parse(i)
+}
+
+/// Parses a time interval string.
+///
+/// See [`interval()`][`crate::interval()`] for supported formats.
+pub fn parse_interval(i: &[u8]) -> IResult<&[u8], Interval, Error<&[u8]>> {
+    parse_interval_with(i, &ParseOptions::new())
+}
+
+/// Parses a time interval string with the given options.
+///
+/// See [`ParseOptions`] for the available options.
+pub fn parse_interval_with<'a>(
+    i: &'a [u8],
+    options: &ParseOptions,
+) -> IResult<&'a [u8], Interval, Error<&'a [u8]>> {
+    let start_and_end = |i| {
+        let (i, start) = terminated(|i| interval_endpoint(i, options), separator("/")).parse(i)?;
+        alt((
+            (|i| parse_duration_with(i, options))
+                .map(|duration| Interval::StartDuration { start, duration }),
+            (|i| interval_end(i, &start, options)).map(|end| Interval::StartEnd { start, end }),
+        ))
+        .parse(i)
+    };
+
+    alt((
+        separated_pair(
+            |i| parse_duration_with(i, options),
+            separator("/"),
+            |i| interval_endpoint(i, options),
+        )
+        .map(|(duration, end)| Interval::DurationEnd { duration, end }),
+        start_and_end,
+    ))
+    .parse(i)
+}
+
+// REPEATING INTERVAL
+
+// "R" [1*DIGIT]
+fn repetitions<'a>(i: &'a [u8], options: &ParseOptions) -> PResult<'a, Option<u32>> {
+    let (i, _) = designator("R", options).parse(i)?;
+    let (rest, digits) = take_while(AsChar::is_dec_digit).parse(i)?;
+
+    if digits.is_empty() {
+        return Ok((rest, None));
+    }
+
+    match parse_number(digits) {
+        Some(n) => Ok((rest, Some(n))),
+        None => Err(Err::Failure(
+            Error::new(i, ErrorKind::OutOfRange).in_component(Component::Repetitions),
+        )),
+    }
+}
+
+/// Parses a recurring time interval string.
+///
+/// See [`repeating_interval()`][`crate::repeating_interval()`] for supported formats.
+pub fn parse_repeating_interval(i: &[u8]) -> IResult<&[u8], RepeatingInterval, Error<&[u8]>> {
+    parse_repeating_interval_with(i, &ParseOptions::new())
+}
+
+/// Parses a recurring time interval string with the given options.
+///
+/// See [`ParseOptions`] for the available options.
+pub fn parse_repeating_interval_with<'a>(
+    i: &'a [u8],
+    options: &ParseOptions,
+) -> IResult<&'a [u8], RepeatingInterval, Error<&'a [u8]>> {
+    separated_pair(
+        |i| repetitions(i, options),
+        separator("/"),
+        |i| parse_interval_with(i, options),
+    )
+    .map(|(repetitions, interval)| RepeatingInterval {
+        repetitions,
+        interval,
+    })
+    .parse(i)
 }
@@ -0,0 +1,377 @@
+// The parsers of the Extended Date/Time Format, see `crate::edtf`.
+
+use alloc::vec::Vec;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::one_of,
+    combinator::{cut, opt},
+    sequence::preceded,
+    Err, IResult, Parser,
+};
+
+use super::{
+    checked_time, component, date_day, date_month, expanded_year_digits, n_digit_in_range,
+    parse_number, separator, take_digits, time_minute, time_second, timezone_hour, timezone_utc,
+    Error, PResult,
+};
+use crate::{
+    edtf::{
+        ComponentQualifiers, Date, Edtf, Endpoint, Interval, Qualifier, Qualifiers, SetMember,
+        Unspecified,
+    },
+    Component, DateTime, ErrorKind, Offset, ParseOptions, Time, TimePrecision,
+};
+
+/// Parses a string of the Extended Date/Time Format, up to Level 2.
+///
+/// See [`edtf`][`crate::edtf`] for the supported formats.
+pub fn parse_edtf(i: &[u8]) -> IResult<&[u8], Edtf, Error<&[u8]>> {
+    alt((
+        edtf_interval.map(Edtf::Interval),
+        edtf_datetime.map(Edtf::DateTime),
+        edtf_date.map(Edtf::Date),
+        edtf_set,
+    ))
+    .parse(i)
+}
+
+// `n` digits of a year, the rightmost of which may be unspecified `X`,
+// as the value with zeros for the unspecified digits and the mask of them
+fn digits_then_x(i: &[u8], n: usize) -> PResult<'_, (u32, u8)> {
+    let digits = i.iter().take(n).take_while(|c| c.is_ascii_digit()).count();
+    let xs = i[digits..]
+        .iter()
+        .take(n - digits)
+        .take_while(|&&c| c == b'X')
+        .count();
+    if digits + xs < n {
+        return Err(Err::Error(Error::unexpected(&i[digits + xs..])));
+    }
+
+    let value = if digits == 0 {
+        0
+    } else {
+        parse_number(&i[..digits]).ok_or(Err::Error(Error::new(i, ErrorKind::OutOfRange)))?
+    };
+    Ok((&i[n..], (value * 10u32.pow(xs as u32), (1 << xs) - 1)))
+}
+
+// Y[-]DDDDD..., a year of more than four digits, or Y[-]D...EN with an exponent
+fn letter_prefixed_year(i: &[u8]) -> PResult<'_, (i32, Option<u32>)> {
+    component(Component::Year, |i| {
+        let (rest, (_, minus, (year, exponent))) = (
+            tag("Y"),
+            opt(tag("-")),
+            alt((
+                (take_digits, preceded(tag("E"), take_digits)).map(|(d, e)| (d, Some(e))),
+                expanded_year_digits.map(|year| (year, None)),
+            )),
+        )
+            .parse(i)?;
+
+        let year = match exponent {
+            // zero times any power of ten
+            Some(_) if year == 0 => 0,
+            Some(exponent) => 10u32
+                .checked_pow(exponent)
+                .and_then(|power| year.checked_mul(power))
+                .filter(|&year| year <= i32::MAX as u32)
+                .ok_or(Err::Error(Error::new(i, ErrorKind::OutOfRange)))?,
+            None => year,
+        } as i32;
+        Ok((rest, (if minus.is_some() { -year } else { year }, exponent)))
+    })
+    .parse(i)
+}
+
+// S<n>, the number of significant digits of a year
+fn significant_digits(i: &[u8]) -> PResult<'_, u32> {
+    component(
+        Component::Year,
+        preceded(tag("S"), |i| match take_digits(i)? {
+            (_, 0) => Err(Err::Error(Error::new(i, ErrorKind::OutOfRange))),
+            parsed => Ok(parsed),
+        }),
+    )
+    .parse(i)
+}
+
+// [-]YYYY
+fn edtf_year(i: &[u8]) -> PResult<'_, (i32, u8)> {
+    component(Component::Year, (opt(tag("-")), |i| digits_then_x(i, 4)))
+        .map(|(minus, (year, unspecified))| {
+            let year = year as i32;
+            (if minus.is_some() { -year } else { year }, unspecified)
+        })
+        .parse(i)
+}
+
+// XX, an unspecified month or day
+fn unspecified_component(i: &[u8]) -> PResult<'_, (u32, u8)> {
+    tag("XX").map(|_| (0, 0b11)).parse(i)
+}
+
+// MM, a season from 21 to 24, or XX
+fn edtf_month(i: &[u8]) -> PResult<'_, (u32, u8)> {
+    alt((
+        date_month.map(|month| (month, 0)),
+        component(Component::Month, |i| n_digit_in_range(i, 2, 21..=24)).map(|season| (season, 0)),
+        unspecified_component,
+    ))
+    .parse(i)
+}
+
+// DD or XX
+fn edtf_day(i: &[u8]) -> PResult<'_, (u32, u8)> {
+    alt((date_day.map(|day| (day, 0)), unspecified_component)).parse(i)
+}
+
+// A component following an unspecified digit has to be unspecified as well.
+fn unspecified_from_the_right<'a>(
+    i: &'a [u8],
+    before: u8,
+    (rest, (value, unspecified)): (&'a [u8], (u32, u8)),
+    component: Component,
+) -> PResult<'a, (u32, u8)> {
+    if before != 0 && unspecified != 0b11 {
+        return Err(Err::Error(Error::unexpected(i).in_component(component)));
+    }
+    Ok((rest, (value, unspecified)))
+}
+
+// ? ~ or %
+fn qualifier(i: &[u8]) -> PResult<'_, Qualifier> {
+    one_of("?~%")
+        .map(|c| match c {
+            '?' => Qualifier::Uncertain,
+            '~' => Qualifier::Approximate,
+            _ => Qualifier::UncertainApproximate,
+        })
+        .parse(i)
+}
+
+// [q]MM[q] or [q]DD[q], a month or day with qualifiers before and after
+fn qualified_component<'a>(
+    i: &'a [u8],
+    parser: fn(&[u8]) -> PResult<'_, (u32, u8)>,
+    before: u8,
+    component: Component,
+) -> PResult<'a, (u32, u8, Qualifiers)> {
+    let (value_input, before_qualifier) = opt(qualifier).parse(i)?;
+    let parsed = parser(value_input)?;
+    let (rest, (value, unspecified)) =
+        unspecified_from_the_right(value_input, before, parsed, component)?;
+    let (rest, after_qualifier) = opt(qualifier).parse(rest)?;
+
+    let qualifiers = Qualifiers {
+        before: before_qualifier,
+        after: after_qualifier,
+    };
+    Ok((rest, (value, unspecified, qualifiers)))
+}
+
+// A date of up to Level 2, YYYY[-MM[-DD]] with unspecified rightmost digits, a season YYYY-SS,
+// or a letter-prefixed year, each component possibly qualified before and after.
+// A year with significant digits has no month.
+fn edtf_date(i: &[u8]) -> PResult<'_, Date> {
+    let (mut rest, (year_before, (year, exponent, calendar_year), significant_digits, year_after)) =
+        (
+            opt(qualifier),
+            alt((
+                letter_prefixed_year.map(|(year, exponent)| (year, exponent, None)),
+                edtf_year.map(|(year, unspecified)| (year, None, Some(unspecified))),
+            )),
+            opt(significant_digits),
+            opt(qualifier),
+        )
+            .parse(i)?;
+    let year_unspecified = calendar_year.unwrap_or(0);
+    let mut date = Date {
+        year,
+        // nothing but the sign of the year is a `-` up to here
+        negative_zero: year == 0 && i[..i.len() - rest.len()].contains(&b'-'),
+        letter_prefix: calendar_year.is_none(),
+        exponent,
+        significant_digits,
+        unspecified: Unspecified {
+            year: year_unspecified,
+            ..Unspecified::default()
+        },
+        qualifiers: ComponentQualifiers {
+            year: Qualifiers {
+                before: year_before,
+                after: year_after,
+            },
+            ..ComponentQualifiers::default()
+        },
+        ..Date::default()
+    };
+
+    if let (Some(_), None, Some(month_input)) =
+        (calendar_year, significant_digits, rest.strip_prefix(b"-"))
+    {
+        let (after_month, (month, unspecified, qualifiers)) =
+            qualified_component(month_input, edtf_month, year_unspecified, Component::Month)?;
+        (rest, date.month) = (after_month, Some(month));
+        (date.unspecified.month, date.qualifiers.month) = (unspecified, qualifiers);
+
+        if let Some(day_input) = rest.strip_prefix(b"-") {
+            if date.season().is_some() {
+                // a season has no days
+                return Err(Err::Error(Error::unexpected(rest)));
+            }
+            let (after_day, (day, unspecified, qualifiers)) =
+                qualified_component(day_input, edtf_day, unspecified, Component::Day)?;
+            (rest, date.day) = (after_day, Some(day));
+            (date.unspecified.day, date.qualifiers.day) = (unspecified, qualifiers);
+        }
+    }
+
+    // the qualifier after the last component is the one of the whole date
+    let last = match (date.month, date.day) {
+        (_, Some(_)) => &mut date.qualifiers.day,
+        (Some(_), None) => &mut date.qualifiers.month,
+        (None, None) => &mut date.qualifiers.year,
+    };
+    date.qualifier = last.after.take();
+
+    if let (Some(month), Some(day), Unspecified { day: 0, .. }) =
+        (date.month, date.day, date.unspecified)
+    {
+        let calendar_date = crate::Date::YMD { year, month, day };
+        if let Err(reason) = calendar_date.validate() {
+            // the date was recognized, no other alternative can do better
+            return Err(Err::Failure(Error::invalid_date(i, reason)));
+        }
+    }
+    Ok((rest, date))
+}
+
+// YYYY-MM-DDThh:mm:ss[(Z|+...|-...)], a complete date and time of Level 0
+fn edtf_datetime(i: &[u8]) -> PResult<'_, DateTime> {
+    let (time_input, date) = edtf_date(i)?;
+    let date = match date {
+        Date {
+            year,
+            letter_prefix: false,
+            negative_zero: _,
+            exponent: None,
+            significant_digits: None,
+            month: Some(month @ 1..=12),
+            day: Some(day),
+            unspecified:
+                Unspecified {
+                    year: 0,
+                    month: 0,
+                    day: 0,
+                },
+            qualifier: None,
+            qualifiers,
+        } if qualifiers == ComponentQualifiers::default() => crate::Date::YMD { year, month, day },
+        _ => return Err(Err::Error(Error::unexpected(time_input))),
+    };
+
+    let (time_input, _) = separator("T").parse(time_input)?;
+    // nothing but a time can follow the `T`
+    let (rest, time) = cut(|i| {
+        let parsed = (
+            // unlike ISO 8601, EDTF has no 24:00 for the end of the day
+            component(Component::Hour, |i| n_digit_in_range(i, 2, 0..=23)),
+            separator(":"),
+            time_minute,
+            separator(":"),
+            time_second,
+            opt(alt((timezone_hour, |i| {
+                timezone_utc(i, &ParseOptions::new())
+            }))),
+        )
+            .map(|(hour, _, minute, _, second, offset)| Time {
+                hour,
+                minute,
+                second,
+                nanosecond: 0,
+                offset: offset.unwrap_or(Offset::Local),
+                precision: TimePrecision::Second,
+            })
+            .parse(i)?;
+        checked_time(i, parsed)
+    })
+    .parse(time_input)?;
+
+    Ok((rest, DateTime { date, time }))
+}
+
+// `..` for an open end, a date, or nothing for an unknown end
+fn edtf_endpoint(i: &[u8]) -> PResult<'_, Endpoint> {
+    alt((
+        tag("..").map(|_| Endpoint::Open),
+        edtf_date.map(Endpoint::Date),
+        unknown_endpoint,
+    ))
+    .parse(i)
+}
+
+// nothing at the end of the input or before the `/`
+fn unknown_endpoint(i: &[u8]) -> PResult<'_, Endpoint> {
+    if i.is_empty() || i.starts_with(b"/") {
+        Ok((i, Endpoint::Unknown))
+    } else {
+        Err(Err::Error(Error::unexpected(i)))
+    }
+}
+
+// <start>/<end>, at least one of which is a date
+fn edtf_interval(i: &[u8]) -> PResult<'_, Interval> {
+    let (rest, (start, _, end)) = (edtf_endpoint, separator("/"), edtf_endpoint).parse(i)?;
+
+    match (start, end) {
+        (Endpoint::Date(_), _) | (_, Endpoint::Date(_)) => Ok((rest, Interval { start, end })),
+        _ => Err(Err::Error(Error::unexpected(i))),
+    }
+}
+
+// [a,b,c..d] for one of the members, {a,b} for all of them
+fn edtf_set(i: &[u8]) -> PResult<'_, Edtf> {
+    let (mut rest, open) = one_of("[{").parse(i)?;
+    let close = if open == '[' { b']' } else { b'}' };
+
+    let mut members = Vec::new();
+    loop {
+        let (after, member) = set_member(rest, members.is_empty())?;
+        members.push(member);
+        match after.first() {
+            // only the last member may be open at its end
+            Some(b',') if !matches!(member, SetMember::Later(_)) => rest = &after[1..],
+            Some(&c) if c == close => {
+                rest = &after[1..];
+                break;
+            }
+            _ => return Err(Err::Error(Error::unexpected(after))),
+        }
+    }
+
+    let set = if open == '[' {
+        Edtf::OneOf(members)
+    } else {
+        Edtf::AllOf(members)
+    };
+    Ok((rest, set))
+}
+
+// a date, a range of dates `a..b`, `..a` as the first member or `a..` as the last one
+fn set_member(i: &[u8], first: bool) -> PResult<'_, SetMember> {
+    if let (true, Some(rest)) = (first, i.strip_prefix(b"..")) {
+        return edtf_date.map(SetMember::Earlier).parse(rest);
+    }
+
+    let (rest, start) = edtf_date(i)?;
+    let Some(after_dots) = rest.strip_prefix(b"..") else {
+        return Ok((rest, SetMember::Date(start)));
+    };
+    match edtf_date(after_dots) {
+        Ok((rest, end)) => Ok((rest, SetMember::Range { start, end })),
+        Err(Err::Error(_)) => Ok((after_dots, SetMember::Later(start))),
+        Err(e) => Err(e),
+    }
+}
@@ -1,16 +1,46 @@
 use super::*;
 use crate::assert_parser;
 
+const OPTS: ParseOptions = ParseOptions::new();
+
 #[test]
 fn test_date_year() {
-    assert_eq!(Ok((&[][..], 2015)), date_year(b"2015"));
-    assert_eq!(Ok((&[][..], -333)), date_year(b"-0333"));
-    assert_eq!(Ok((&b"-"[..], 2015)), date_year(b"2015-"));
-    date_year(b"2023").unwrap();
-    date_year(b"+2023").unwrap();
-    date_year(b"-2023").unwrap();
-    assert!(date_year(b"abcd").is_err());
-    assert!(date_year(b"2a03").is_err());
+    assert_eq!(Ok((&[][..], 2015)), date_year(b"2015", &OPTS));
+    assert_eq!(Ok((&[][..], -333)), date_year(b"-0333", &OPTS));
+    assert_eq!(Ok((&b"-"[..], 2015)), date_year(b"2015-", &OPTS));
+    date_year(b"2023", &OPTS).unwrap();
+    date_year(b"+2023", &OPTS).unwrap();
+    date_year(b"-2023", &OPTS).unwrap();
+    assert!(date_year(b"abcd", &OPTS).is_err());
+    assert!(date_year(b"2a03", &OPTS).is_err());
+}
+
+#[test]
+fn test_date_year_expanded() {
+    assert_eq!(
+        Ok((&b"-01-01"[..], 12345)),
+        date_year(b"+12345-01-01", &OPTS)
+    );
+    assert_eq!(
+        Ok((&b"-01-01"[..], -12345)),
+        date_year(b"-12345-01-01", &OPTS)
+    );
+    // without a separator or a sign the year is taken to have four digits
+    assert_eq!(Ok((&b"50101"[..], 1234)), date_year(b"+123450101", &OPTS));
+    assert_eq!(
+        Ok((&b"5-01-01"[..], 1234)),
+        date_year(b"12345-01-01", &OPTS)
+    );
+
+    let expanded = ParseOptions::new().expanded_year(2);
+    assert_eq!(
+        Ok((&b"0101"[..], 12345)),
+        date_year(b"+0123450101", &expanded)
+    );
+    assert_eq!(Ok((&[][..], -2015)), date_year(b"-002015", &expanded));
+    assert!(date_year(b"2015", &expanded).is_err());
+    assert!(date_year(b"+2015", &expanded).is_err());
+    assert!(date_year(b"+12345", &expanded).is_err());
 }
 
 #[test]
@@ -100,18 +130,47 @@ fn test_time_with_timezone() {
 
 #[test]
 fn test_date_iso_week_date() {
-    assert!(date_iso_week(b"2015-W06-8").is_err());
-    assert!(date_iso_week(b"2015-W068").is_err());
-    assert!(date_iso_week(b"2015-W06-0").is_err());
-    assert!(date_iso_week(b"2015-W00-2").is_err());
-    assert!(date_iso_week(b"2015-W54-2").is_err());
-    assert!(date_iso_week(b"2015-W542").is_err());
+    assert!(date_iso_week(b"2015-W06-8", &OPTS).is_err());
+    assert!(date_iso_week(b"2015-W068", &OPTS).is_err());
+    assert!(date_iso_week(b"2015-W06-0", &OPTS).is_err());
+    assert!(date_iso_week(b"2015-W00-2", &OPTS).is_err());
+    assert!(date_iso_week(b"2015-W54-2", &OPTS).is_err());
+    assert!(date_iso_week(b"2015-W542", &OPTS).is_err());
+}
+
+#[rustfmt::skip]
+#[test]
+fn date_roundtrip_reduced_precision() {
+    assert_parser!(parse_date, "2015-11", Date::YearMonth { year: 2015, month: 11 });
+    assert_parser!(parse_date, "2015-W45", Date::YearWeek { year: 2015, ww: 45 });
+    assert_parser!(parse_date, "2015", Date::Year { year: 2015 });
+    assert_parser!(parse_date, "-0333", Date::Year { year: -333 });
+    assert_parser!(parse_date, "20", Date::Century { century: 20 });
+    assert_parser!(parse_date, "-03", Date::Century { century: -3 });
+}
+
+#[test]
+fn test_date_reduced_precision() {
+    assert_eq!(
+        Ok((&[][..], Date::YearWeek { year: 2015, ww: 45 })),
+        parse_date(b"2015W45")
+    );
+    assert!(parse_date(b"2015-13").is_err());
+    assert!(parse_date(b"2015-W54").is_err());
+    assert!(parse_date(b"20151").is_err());
+    assert!(parse_date(b"2").is_err());
+    assert!(parse_date(b"2015-1").is_err());
+    // YYYYMM is not a valid basic format
+    assert!(parse_date(b"201511").is_err());
+    // a datetime requires a complete date
+    assert!(parse_datetime(b"2015-11T10:00").is_err());
+    assert!(parse_datetime(b"2015T10:00").is_err());
 }
 
 #[test]
 fn test_date_ordinal_date() {
     // not valid here either
-    assert!(date_ordinal(b"2015-400").is_err());
+    assert!(date_ordinal(b"2015-400", &OPTS).is_err());
 }
 
 #[test]
@@ -129,7 +188,7 @@ fn format_equivalence() {
         parse_datetime(b"20010203T04:05:06+0700")
     );
     assert_eq!(
-        parse_datetime(b"2001-02-03T04:05:00+07:00"),
+        parse_datetime(b"2001-02-03T04:05+07:00"),
         parse_datetime(b"20010203T0405+0700")
     );
     assert_eq!(
@@ -141,6 +200,11 @@ fn format_equivalence() {
         parse_datetime(b"2001-02-03T040506+0700")
     );
     assert_eq!(
+        parse_datetime(b"20010203T040506+0000"),
+        parse_datetime(b"2001-02-03T04:05:06+00:00")
+    );
+    // `Z` is told apart from an explicit `+00:00`
+    assert_ne!(
         parse_datetime(b"20010203T040506+0000"),
         parse_datetime(b"20010203T040506Z")
     );
@@ -167,14 +231,14 @@ fn datetime_roundtrip_ymd() {
         parse_datetime, "2001-02-03T04:05:06.000+07:00",
         DateTime{
             date: Date::YMD{year: 2001, month: 2, day: 3},
-            time: Time { hour: 4, minute: 5, second: 6, millisecond: 0, tz_offset_hours: 7, tz_offset_minutes: 0 }
+            time: Time { hour: 4, minute: 5, second: 6, nanosecond: 0, offset: Offset::Fixed(UtcOffset::from_hms(7, 0, 0).unwrap()), precision: TimePrecision::Second }
         }
     );
     assert_parser!(
         parse_datetime, "2001-02-03T04:05:06.001+07:00",
         DateTime{
             date: Date::YMD{year: 2001, month: 2, day: 3},
-            time: Time { hour: 4, minute: 5, second: 6, millisecond: 1, tz_offset_hours: 7, tz_offset_minutes: 0 }
+            time: Time { hour: 4, minute: 5, second: 6, nanosecond: 1_000_000, offset: Offset::Fixed(UtcOffset::from_hms(7, 0, 0).unwrap()), precision: TimePrecision::Second }
         }
     );
 }
@@ -186,7 +250,7 @@ fn datetime_roundtrip_week() {
         parse_datetime, "2015-W05-6T04:05:06.001+07:00",
         DateTime{
             date: Date::Week { year: 2015, ww: 5, d: 6 },
-            time: Time { hour: 4, minute: 5, second: 6, millisecond: 1, tz_offset_hours: 7, tz_offset_minutes: 0 }
+            time: Time { hour: 4, minute: 5, second: 6, nanosecond: 1_000_000, offset: Offset::Fixed(UtcOffset::from_hms(7, 0, 0).unwrap()), precision: TimePrecision::Second }
         }
     );
 }
@@ -198,7 +262,7 @@ fn datetime_roundtrip_ordinal() {
         parse_datetime, "2001-035T04:05:06.001+07:00",
         DateTime{
             date: Date::Ordinal { year: 2001, ddd: 35 },
-            time: Time { hour: 4, minute: 5, second: 6, millisecond: 1, tz_offset_hours: 7, tz_offset_minutes: 0 }
+            time: Time { hour: 4, minute: 5, second: 6, nanosecond: 1_000_000, offset: Offset::Fixed(UtcOffset::from_hms(7, 0, 0).unwrap()), precision: TimePrecision::Second }
         }
     );
 }
@@ -212,145 +276,309 @@ fn disallows_notallowed() {
 
 #[test]
 fn test_duration_year() {
-    assert_eq!(Ok((&[][..], 2019)), duration_year(b"2019Y"));
-    assert_eq!(Ok((&[][..], 0)), duration_year(b"0Y"));
-    assert_eq!(Ok((&[][..], 10000)), duration_year(b"10000Y"));
-    assert!(duration_year(b"abcd").is_err());
-    assert!(duration_year(b"-1").is_err());
+    assert_eq!(Ok((&[][..], 2019)), duration_year(b"2019Y", &OPTS));
+    assert_eq!(Ok((&[][..], 0)), duration_year(b"0Y", &OPTS));
+    assert_eq!(Ok((&[][..], 10000)), duration_year(b"10000Y", &OPTS));
+    assert!(duration_year(b"abcd", &OPTS).is_err());
+    assert!(duration_year(b"-1", &OPTS).is_err());
 }
 
 #[test]
 fn test_duration_month() {
-    assert_eq!(Ok((&[][..], 6)), duration_month(b"6M"));
-    assert_eq!(Ok((&[][..], 0)), duration_month(b"0M"));
-    assert_eq!(Ok((&[][..], 12)), duration_month(b"12M"));
-    assert!(duration_month(b"ab").is_err());
-    assert!(duration_month(b"-1").is_err());
-    assert!(duration_month(b"13").is_err());
+    assert_eq!(Ok((&[][..], 6)), duration_month(b"6M", &OPTS));
+    assert_eq!(Ok((&[][..], 0)), duration_month(b"0M", &OPTS));
+    assert_eq!(Ok((&[][..], 12)), duration_month(b"12M", &OPTS));
+    assert!(duration_month(b"ab", &OPTS).is_err());
+    assert!(duration_month(b"-1", &OPTS).is_err());
+    assert!(duration_month(b"13", &OPTS).is_err());
 }
 
 #[test]
 fn test_duration_week() {
-    assert_eq!(Ok((&[][..], 26)), duration_week(b"26W"));
-    assert_eq!(Ok((&[][..], 0)), duration_week(b"0W"));
-    assert_eq!(Ok((&[][..], 52)), duration_week(b"52W"));
-    assert!(duration_week(b"ab").is_err());
-    assert!(duration_week(b"-1").is_err());
-    assert!(duration_week(b"53").is_err());
+    assert_eq!(Ok((&[][..], 26)), duration_week(b"26W", &OPTS));
+    assert_eq!(Ok((&[][..], 0)), duration_week(b"0W", &OPTS));
+    assert_eq!(Ok((&[][..], 52)), duration_week(b"52W", &OPTS));
+    assert!(duration_week(b"ab", &OPTS).is_err());
+    assert!(duration_week(b"-1", &OPTS).is_err());
+    assert!(duration_week(b"53", &OPTS).is_err());
 }
 
 #[test]
 fn test_duration_day() {
-    assert_eq!(Ok((&[][..], 16)), duration_day(b"16D"));
-    assert_eq!(Ok((&[][..], 0)), duration_day(b"0D"));
-    assert_eq!(Ok((&[][..], 31)), duration_day(b"31D"));
-    assert!(duration_day(b"ab").is_err());
-    assert!(duration_day(b"-1").is_err());
-    assert!(duration_day(b"32").is_err());
+    assert_eq!(Ok((&[][..], (16, None))), duration_day(b"16D", &OPTS));
+    assert_eq!(Ok((&[][..], (0, None))), duration_day(b"0D", &OPTS));
+    assert_eq!(Ok((&[][..], (31, None))), duration_day(b"31D", &OPTS));
+    assert!(duration_day(b"ab", &OPTS).is_err());
+    assert!(duration_day(b"-1", &OPTS).is_err());
+    assert!(duration_day(b"32", &OPTS).is_err());
+    assert_eq!(
+        Ok((&[][..], (0, Some(500_000_000)))),
+        duration_day(b"0.5D", &OPTS)
+    );
 }
 
 #[test]
 fn test_duration_hour() {
-    assert_eq!(Ok((&[][..], 12)), duration_hour(b"12H"));
-    assert_eq!(Ok((&[][..], 0)), duration_hour(b"0H"));
-    assert_eq!(Ok((&[][..], 24)), duration_hour(b"24H"));
-    assert!(duration_hour(b"ab").is_err());
-    assert!(duration_hour(b"-1").is_err());
-    assert!(duration_hour(b"25").is_err());
+    assert_eq!(Ok((&[][..], (12, None))), duration_hour(b"12H", &OPTS));
+    assert_eq!(Ok((&[][..], (0, None))), duration_hour(b"0H", &OPTS));
+    assert_eq!(Ok((&[][..], (24, None))), duration_hour(b"24H", &OPTS));
+    assert!(duration_hour(b"ab", &OPTS).is_err());
+    assert!(duration_hour(b"-1", &OPTS).is_err());
+    assert!(duration_hour(b"25", &OPTS).is_err());
+    assert_eq!(
+        Ok((&[][..], (2, Some(500_000_000)))),
+        duration_hour(b"2.5H", &OPTS)
+    );
+    assert_eq!(
+        Ok((&[][..], (2, Some(250_000_000)))),
+        duration_hour(b"2,25H", &OPTS)
+    );
 }
 
 #[test]
 fn test_duration_minute() {
-    assert_eq!(Ok((&[][..], 30)), duration_minute(b"30M"));
-    assert_eq!(Ok((&[][..], 0)), duration_minute(b"0M"));
-    assert_eq!(Ok((&[][..], 60)), duration_minute(b"60M"));
-    assert!(duration_minute(b"ab").is_err());
-    assert!(duration_minute(b"-1").is_err());
-    assert!(duration_minute(b"61").is_err());
+    assert_eq!(Ok((&[][..], (30, None))), duration_minute(b"30M", &OPTS));
+    assert_eq!(Ok((&[][..], (0, None))), duration_minute(b"0M", &OPTS));
+    assert_eq!(Ok((&[][..], (60, None))), duration_minute(b"60M", &OPTS));
+    assert!(duration_minute(b"ab", &OPTS).is_err());
+    assert!(duration_minute(b"-1", &OPTS).is_err());
+    assert!(duration_minute(b"61", &OPTS).is_err());
+    assert_eq!(
+        Ok((&[][..], (1, Some(750_000_000)))),
+        duration_minute(b"1.75M", &OPTS)
+    );
 }
 
 #[test]
-fn test_duration_second_and_millisecond1() {
+fn test_duration_second_and_nanosecond1() {
     assert_eq!(
         Ok((&[][..], (30, 0))),
-        duration_second_and_millisecond(b"30S")
+        duration_second_and_nanosecond(b"30S", &OPTS)
     );
     assert_eq!(
         Ok((&[][..], (0, 0))),
-        duration_second_and_millisecond(b"0S")
+        duration_second_and_nanosecond(b"0S", &OPTS)
     );
     assert_eq!(
         Ok((&[][..], (60, 0))),
-        duration_second_and_millisecond(b"60S")
+        duration_second_and_nanosecond(b"60S", &OPTS)
     );
     assert_eq!(
-        Ok((&[][..], (1, 230))),
-        duration_second_and_millisecond(b"1,23S")
+        Ok((&[][..], (1, 230_000_000))),
+        duration_second_and_nanosecond(b"1,23S", &OPTS)
     );
     assert_eq!(
-        Ok((&[][..], (2, 340))),
-        duration_second_and_millisecond(b"2.34S")
+        Ok((&[][..], (2, 340_000_000))),
+        duration_second_and_nanosecond(b"2.34S", &OPTS)
     );
-    assert!(duration_second_and_millisecond(b"abS").is_err());
-    assert!(duration_second_and_millisecond(b"-1S").is_err());
+    assert!(duration_second_and_nanosecond(b"abS", &OPTS).is_err());
+    assert!(duration_second_and_nanosecond(b"--1S", &OPTS).is_err());
+}
+
+#[test]
+fn test_duration_negative_units() {
+    assert_eq!(Ok((&[][..], -2019)), duration_year(b"-2019Y", &OPTS));
+    assert_eq!(Ok((&[][..], -2)), duration_week(b"-2W", &OPTS));
+    assert_eq!(Ok((&[][..], (-16, None))), duration_day(b"-16D", &OPTS));
+    assert_eq!(
+        Ok((&[][..], (-1, -500_000_000))),
+        duration_second_and_nanosecond(b"-1.5S", &OPTS)
+    );
+    assert_eq!(
+        Ok((&[][..], (0, -500_000_000))),
+        duration_second_and_nanosecond(b"-0.5S", &OPTS)
+    );
+    assert_eq!(
+        Ok((&[][..], (0, -30, 0, 0))),
+        duration_time(b"-0.5H", &OPTS)
+    );
+    assert_eq!(Ok((&[][..], (1, -2, 0, 0))), duration_time(b"1H-2M", &OPTS));
+    assert!(duration_year(b"-Y", &OPTS).is_err());
+    // the sign doesn't count towards the digits
+    assert_eq!(
+        Ok((&[][..], -2147483647)),
+        duration_year(b"-2147483647Y", &OPTS)
+    );
+    assert!(matches!(
+        duration_year(b"-2147483648Y", &OPTS),
+        Err(Err::Failure(_))
+    ));
 }
 
 #[test]
 fn test_duration_time() {
-    assert_eq!(Ok((&[][..], (1, 2, 3, 0))), duration_time(b"1H2M3S"));
-    assert_eq!(Ok((&[][..], (10, 12, 30, 0))), duration_time(b"10H12M30S"));
-    assert_eq!(Ok((&[][..], (1, 0, 3, 0))), duration_time(b"1H3S"));
-    assert_eq!(Ok((&[][..], (0, 2, 0, 0))), duration_time(b"2M"));
-    assert_eq!(Ok((&[][..], (1, 2, 3, 400))), duration_time(b"1H2M3,4S"));
-    assert_eq!(Ok((&[][..], (1, 2, 3, 400))), duration_time(b"1H2M3.4S"));
-    assert_eq!(Ok((&[][..], (0, 0, 0, 123))), duration_time(b"0,123S"));
-    assert_eq!(Ok((&[][..], (0, 0, 0, 123))), duration_time(b"0.123S"));
+    assert_eq!(Ok((&[][..], (1, 2, 3, 0))), duration_time(b"1H2M3S", &OPTS));
+    assert_eq!(
+        Ok((&[][..], (10, 12, 30, 0))),
+        duration_time(b"10H12M30S", &OPTS)
+    );
+    assert_eq!(Ok((&[][..], (1, 0, 3, 0))), duration_time(b"1H3S", &OPTS));
+    assert_eq!(Ok((&[][..], (0, 2, 0, 0))), duration_time(b"2M", &OPTS));
+    assert_eq!(
+        Ok((&[][..], (1, 2, 3, 400_000_000))),
+        duration_time(b"1H2M3,4S", &OPTS)
+    );
+    assert_eq!(
+        Ok((&[][..], (1, 2, 3, 400_000_000))),
+        duration_time(b"1H2M3.4S", &OPTS)
+    );
+    assert_eq!(
+        Ok((&[][..], (0, 0, 0, 123_000_000))),
+        duration_time(b"0,123S", &OPTS)
+    );
+    assert_eq!(
+        Ok((&[][..], (0, 0, 0, 123_000_000))),
+        duration_time(b"0.123S", &OPTS)
+    );
+    assert_eq!(Ok((&[][..], (2, 30, 0, 0))), duration_time(b"2.5H", &OPTS));
+    assert_eq!(
+        Ok((&[][..], (1, 2, 15, 0))),
+        duration_time(b"1H2.25M", &OPTS)
+    );
+    // only the lowest-order component may have a fraction
+    assert_eq!(
+        Ok((&b"30M"[..], (2, 30, 0, 0))),
+        duration_time(b"2.5H30M", &OPTS)
+    );
 }
 
 #[test]
 fn test_duration_ymdhms_error() {
-    assert!(duration_ymdhms(b"").is_err());
-    assert!(duration_ymdhms(b"P").is_err()); // empty duration is not 0 seconds
-    assert!(duration_ymdhms(b"1Y2M3DT4H5M6S").is_err()); // missing P at start
-    assert!(duration_ymdhms(b"T4H5M6S").is_err()); // missing P, required even if no YMD part
+    assert!(duration_ymdhms(b"", &OPTS).is_err());
+    assert!(duration_ymdhms(b"P", &OPTS).is_err()); // empty duration is not 0 seconds
+    assert!(duration_ymdhms(b"1Y2M3DT4H5M6S", &OPTS).is_err()); // missing P at start
+    assert!(duration_ymdhms(b"T4H5M6S", &OPTS).is_err()); // missing P, required even if no YMD part
+}
+
+#[test]
+fn test_duration_ymdhms_weeks() {
+    assert_eq!(
+        Ok((
+            &[][..],
+            Duration::YMDHMS {
+                year: 0,
+                month: 0,
+                week: 1,
+                day: 2,
+                hour: 0,
+                minute: 0,
+                second: 0,
+                nanosecond: 0,
+            }
+        )),
+        duration_ymdhms(b"P1W2D", &OPTS)
+    );
+    assert!(duration_ymdhms(b"P1W", &OPTS).is_err()); // weeks alone are `duration_weeks`
 }
 
 #[test]
 fn test_duration_weeks_error() {
-    assert!(duration_weeks(b"").is_err());
-    assert!(duration_weeks(b"P").is_err()); // empty duration is not 0 seconds
-    assert!(duration_weeks(b"P1").is_err()); // missing W after number
-    assert!(duration_weeks(b"PW").is_err()); // missing number
+    assert!(duration_weeks(b"", &OPTS).is_err());
+    assert!(duration_weeks(b"P", &OPTS).is_err()); // empty duration is not 0 seconds
+    assert!(duration_weeks(b"P1", &OPTS).is_err()); // missing W after number
+    assert!(duration_weeks(b"PW", &OPTS).is_err()); // missing number
 }
 
 #[test]
 fn test_duration_datetime_error() {
-    assert!(duration_datetime(b"").is_err());
-    assert!(duration_datetime(b"P").is_err()); // empty duration is not 0 seconds
-    assert!(duration_datetime(b"0001-02-03T04:05:06").is_err()); // missing P at start
+    assert!(duration_datetime(b"", &OPTS).is_err());
+    assert!(duration_datetime(b"P", &OPTS).is_err()); // empty duration is not 0 seconds
+    assert!(duration_datetime(b"0001-02-03T04:05:06", &OPTS).is_err()); // missing P at start
+    assert!(duration_datetime(b"P0001-02-31T04:05:06", &OPTS).is_err()); // beyond 30 days
+    assert!(duration_datetime(b"P12345-02-03T04:05:06", &OPTS).is_err()); // beyond four digits
+}
+
+#[test]
+fn test_duration_datetime() {
+    let expected = Duration::YMDHMS {
+        year: 1,
+        month: 2,
+        week: 0,
+        day: 3,
+        hour: 4,
+        minute: 5,
+        second: 6,
+        nanosecond: 0,
+    };
+    assert_eq!(
+        Ok((&[][..], expected)),
+        duration_datetime(b"P0001-02-03T04:05:06", &OPTS)
+    );
+    assert_eq!(
+        Ok((&[][..], expected)),
+        duration_datetime(b"P00010203T040506", &OPTS)
+    );
+    // neither an offset nor the other format's separators are consumed
+    assert_eq!(
+        Ok((&b"Z"[..], expected)),
+        duration_datetime(b"P0001-02-03T04:05:06Z", &OPTS)
+    );
+    assert_eq!(
+        Ok((
+            &b":05:06"[..],
+            Duration::YMDHMS {
+                year: 1,
+                month: 2,
+                week: 0,
+                day: 3,
+                hour: 4,
+                minute: 0,
+                second: 0,
+                nanosecond: 0,
+            }
+        )),
+        duration_datetime(b"P00010203T04:05:06", &OPTS)
+    );
 }
 
 #[rustfmt::skip]
 #[test]
-fn test_duration_second_and_millisecond2() {
+fn test_duration_second_and_nanosecond2() {
     assert_parser!(
         parse_duration, "PT30S",
-        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 0, second: 30, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, week: 0, day: 0, hour: 0, minute: 0, second: 30, nanosecond: 0 }
 
     );
 
     assert_parser!(
         parse_duration, "PT30.123S",
-        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 0, second: 30, millisecond: 123 }
+        Duration::YMDHMS { year: 0, month: 0, week: 0, day: 0, hour: 0, minute: 0, second: 30, nanosecond: 123_000_000 }
 
     );
 
     assert_parser!(
         parse_duration, "P2021Y11M16DT23H26M59.123S",
-        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 23, minute: 26, second: 59, millisecond: 123 }
+        Duration::YMDHMS { year: 2021, month: 11, week: 0, day: 16, hour: 23, minute: 26, second: 59, nanosecond: 123_000_000 }
     );
 }
 
+#[rustfmt::skip]
+#[test]
+fn test_duration_negative() {
+    assert_parser!(
+        parse_duration, "-P3DT4H",
+        Duration::YMDHMS { year: 0, month: 0, week: 0, day: -3, hour: -4, minute: 0, second: 0, nanosecond: 0 }
+    );
+    assert_parser!(
+        parse_duration, "-PT1.500S",
+        Duration::YMDHMS { year: 0, month: 0, week: 0, day: 0, hour: 0, minute: 0, second: -1, nanosecond: -500_000_000 }
+    );
+    assert_parser!(
+        parse_duration, "P1DT-1H",
+        Duration::YMDHMS { year: 0, month: 0, week: 0, day: 1, hour: -1, minute: 0, second: 0, nanosecond: 0 }
+    );
+    assert_parser!(parse_duration, "-P2W", Duration::Weeks(-2));
+
+    assert_eq!(parse_duration(b"PT-1.5S"), parse_duration(b"-PT1.5S"));
+    assert_eq!(parse_duration(b"-P-1D"), parse_duration(b"P1D"));
+    assert_eq!(
+        Ok((&[][..], Duration::YMDHMS { year: -1, month: -2, week: 0, day: -3, hour: -4, minute: -5, second: -6, nanosecond: 0 })),
+        parse_duration(b"-P0001-02-03T04:05:06")
+    );
+    assert!(parse_duration(b"P-0001-02-03T04:05:06").is_err());
+    assert!(parse_duration(b"--P1D").is_err());
+}
+
 #[rustfmt::skip]
 #[test]
 fn duration_roundtrip() {
@@ -360,55 +588,55 @@ fn duration_roundtrip() {
 
     assert_parser!(
         parse_duration, "P2021Y11M16DT23H26M59.123S",
-        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 23, minute: 26, second: 59, millisecond: 123 }
+        Duration::YMDHMS { year: 2021, month: 11, week: 0, day: 16, hour: 23, minute: 26, second: 59, nanosecond: 123_000_000 }
     );
     assert_parser!(
         parse_duration, "P2021Y11M16DT23H26M59S",
-        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 23, minute: 26, second: 59, millisecond: 0 }
+        Duration::YMDHMS { year: 2021, month: 11, week: 0, day: 16, hour: 23, minute: 26, second: 59, nanosecond: 0 }
     );
     assert_parser!(
         parse_duration, "P2021Y11M16DT23H26M",
-        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 23, minute: 26, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 2021, month: 11, week: 0, day: 16, hour: 23, minute: 26, second: 0, nanosecond: 0 }
     );
     assert_parser!(
         parse_duration, "P2021Y11M16DT23H",
-        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 23, minute: 0, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 2021, month: 11, week: 0, day: 16, hour: 23, minute: 0, second: 0, nanosecond: 0 }
     );
     assert_parser!(
         parse_duration, "P2021Y11M16D",
-        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 0, minute: 0, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 2021, month: 11, week: 0, day: 16, hour: 0, minute: 0, second: 0, nanosecond: 0 }
     );
     assert_parser!(
         parse_duration, "P2021Y11M16DT1S",
-        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 0, minute: 0, second: 1, millisecond: 0 }
+        Duration::YMDHMS { year: 2021, month: 11, week: 0, day: 16, hour: 0, minute: 0, second: 1, nanosecond: 0 }
     );
     assert_parser!(
         parse_duration, "P2021Y11M16DT0.471S",
-        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 0, minute: 0, second: 0, millisecond: 471 }
+        Duration::YMDHMS { year: 2021, month: 11, week: 0, day: 16, hour: 0, minute: 0, second: 0, nanosecond: 471_000_000 }
     );
     assert_parser!(
         parse_duration, "P2021Y11M16DT0.042S",
-        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 0, minute: 0, second: 0, millisecond: 42 }
+        Duration::YMDHMS { year: 2021, month: 11, week: 0, day: 16, hour: 0, minute: 0, second: 0, nanosecond: 42_000_000 }
     );
     assert_parser!(
         parse_duration, "P2021Y11M16DT0.004S",
-        Duration::YMDHMS { year: 2021, month: 11, day: 16, hour: 0, minute: 0, second: 0, millisecond: 4 }
+        Duration::YMDHMS { year: 2021, month: 11, week: 0, day: 16, hour: 0, minute: 0, second: 0, nanosecond: 4_000_000 }
     );
     assert_parser!(
         parse_duration, "P2021Y11M",
-        Duration::YMDHMS { year: 2021, month: 11, day: 0, hour: 0, minute: 0, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 2021, month: 11, week: 0, day: 0, hour: 0, minute: 0, second: 0, nanosecond: 0 }
     );
     assert_parser!(
         parse_duration, "P11M",
-        Duration::YMDHMS { year: 0, month: 11, day: 0, hour: 0, minute: 0, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 11, week: 0, day: 0, hour: 0, minute: 0, second: 0, nanosecond: 0 }
     );
     assert_parser!(
         parse_duration, "P16D",
-        Duration::YMDHMS { year: 0, month: 0, day: 16, hour: 0, minute: 0, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, week: 0, day: 16, hour: 0, minute: 0, second: 0, nanosecond: 0 }
     );
     assert_parser!(
         parse_duration, "P0D",
-        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 0, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, week: 0, day: 0, hour: 0, minute: 0, second: 0, nanosecond: 0 }
     );
 }
 
@@ -417,11 +645,11 @@ fn duration_roundtrip() {
 fn duration_multi_digit_hour() {
     assert_parser!(
         parse_duration, "PT12H",
-        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 12, minute: 0, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, week: 0, day: 0, hour: 12, minute: 0, second: 0, nanosecond: 0 }
     );
     assert_parser!(
         parse_duration, "PT8760H",
-        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 365*24, minute: 0, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, week: 0, day: 0, hour: 365*24, minute: 0, second: 0, nanosecond: 0 }
     );
 }
 
@@ -430,11 +658,11 @@ fn duration_multi_digit_hour() {
 fn duration_multi_digit_minute() {
     assert_parser!(
         parse_duration, "PT15M",
-        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 15, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, week: 0, day: 0, hour: 0, minute: 15, second: 0, nanosecond: 0 }
     );
     assert_parser!(
         parse_duration, "PT600M",
-        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 600, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, week: 0, day: 0, hour: 0, minute: 600, second: 0, nanosecond: 0 }
     );
 }
 
@@ -443,12 +671,12 @@ fn duration_multi_digit_minute() {
 fn duration_multi_digit_second() {
     assert_parser!(
         parse_duration, "PT16S",
-        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 0, second: 16, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, week: 0, day: 0, hour: 0, minute: 0, second: 16, nanosecond: 0 }
     );
 
     assert_parser!(
         parse_duration, "PT900S",
-        Duration::YMDHMS { year: 0, month: 0, day: 0, hour: 0, minute: 0, second: 900, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, week: 0, day: 0, hour: 0, minute: 0, second: 900, nanosecond: 0 }
     );
 }
 
@@ -457,12 +685,175 @@ fn duration_multi_digit_second() {
 fn duration_multi_digit_day() {
     assert_parser!(
         parse_duration, "P365D",
-        Duration::YMDHMS { year: 0, month: 0, day: 365, hour: 0, minute: 0, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, week: 0, day: 365, hour: 0, minute: 0, second: 0, nanosecond: 0 }
     );
     assert_parser!(
         parse_duration, "P36500D",
-        Duration::YMDHMS { year: 0, month: 0, day: 36500, hour: 0, minute: 0, second: 0, millisecond: 0 }
+        Duration::YMDHMS { year: 0, month: 0, week: 0, day: 36500, hour: 0, minute: 0, second: 0, nanosecond: 0 }
+    );
+}
+
+#[test]
+fn test_abbreviated_month_day() {
+    assert_eq!(
+        Ok((&[][..], (Some(3), 14))),
+        abbreviated_month_day(b"03-14")
+    );
+    assert_eq!(Ok((&[][..], (Some(3), 14))), abbreviated_month_day(b"0314"));
+    assert_eq!(Ok((&[][..], (None, 14))), abbreviated_month_day(b"14"));
+    assert_eq!(Ok((&b"T"[..], (None, 12))), abbreviated_month_day(b"12T"));
+    assert!(abbreviated_month_day(b"3-14").is_err());
+}
+
+#[test]
+fn test_interval_end() {
+    let start = IntervalEndpoint::DateTime(parse_datetime(b"2007-12-14T13:30").unwrap().1);
+    let date = |year, month, day| Date::YMD { year, month, day };
+    let end = |i| interval_end(i, &start, &OPTS).map(|(_, end)| end);
+
+    assert_eq!(Ok(date(2007, 12, 14)), end(b"15:30").map(|end| end.date()));
+    assert_eq!(
+        Ok(IntervalEndpoint::Date(date(2008, 1, 2))),
+        end(b"2008-01-02")
+    );
+    assert_eq!(Ok(IntervalEndpoint::Date(date(2007, 12, 15))), end(b"15"));
+    assert_eq!(
+        Ok(IntervalEndpoint::Date(date(2008, 3, 14))),
+        end(b"2008-03-14")
+    );
+    // a time rather than a month and day after a start with a time
+    assert_eq!(
+        Ok((date(2007, 12, 14), 16, 14)),
+        end(b"1614").map(|end| match end {
+            IntervalEndpoint::DateTime(dt) => (dt.date, dt.time.hour, dt.time.minute),
+            IntervalEndpoint::Date(date) => (date, 0, 0),
+        })
+    );
+    // an abbreviated end can't lie before the start
+    assert!(matches!(end(b"0314"), Err(Err::Failure(_))));
+    assert!(matches!(end(b"13"), Err(Err::Failure(_))));
+    assert_eq!(
+        Ok(IntervalEndpoint::Date(Date::YearMonth {
+            year: 2008,
+            month: 3
+        })),
+        end(b"2008-03")
+    );
+    assert_eq!(
+        Ok(IntervalEndpoint::Date(Date::Year { year: 3015 })),
+        end(b"+3015")
+    );
+    // a week date is abbreviated by weeks, not months
+    let start = IntervalEndpoint::Date(Date::Week {
+        year: 2015,
+        ww: 45,
+        d: 1,
+    });
+    assert!(interval_end(b"03-14", &start, &OPTS).is_err());
+}
+
+#[test]
+fn test_interval_error() {
+    assert!(parse_interval(b"").is_err());
+    assert!(parse_interval(b"2015-06-26").is_err()); // missing end
+    assert!(parse_interval(b"2015-06-26/").is_err());
+    assert!(parse_interval(b"P1Y/P1Y").is_err()); // two durations
+    assert!(parse_interval(b"2015-06-26T10:00/T12:00").is_err());
+}
+
+#[test]
+fn test_repetitions() {
+    assert_eq!(Ok((&b"/"[..], Some(5))), repetitions(b"R5/", &OPTS));
+    assert_eq!(Ok((&b"/"[..], None)), repetitions(b"R/", &OPTS));
+    assert!(repetitions(b"5/", &OPTS).is_err());
+    assert!(repetitions(b"R99999999999/", &OPTS).is_err());
+}
+
+#[test]
+fn test_repeating_interval_error() {
+    assert!(parse_repeating_interval(b"R5").is_err());
+    assert!(parse_repeating_interval(b"R5/").is_err());
+    assert!(parse_repeating_interval(b"R-1/2008-03-01/P1D").is_err());
+    assert!(parse_repeating_interval(b"2008-03-01/P1D").is_err());
+}
+
+#[test]
+fn test_rfc3339_secfrac() {
+    assert_eq!(Ok((&[][..], 520_000_000)), rfc3339_secfrac(b".52"));
+    assert_eq!(Ok((&b"Z"[..], 1)), rfc3339_secfrac(b".000000001Z"));
+    assert!(rfc3339_secfrac(b".").is_err());
+    assert!(rfc3339_secfrac(b",5").is_err());
+}
+
+#[test]
+fn test_rfc3339_offset() {
+    assert_eq!(Ok((&[][..], Offset::Utc)), rfc3339_offset(b"Z"));
+    assert_eq!(Ok((&[][..], Offset::Utc)), rfc3339_offset(b"z"));
+    assert_eq!(
+        Ok((
+            &[][..],
+            Offset::Fixed(UtcOffset::from_hms(-8, 0, 0).unwrap())
+        )),
+        rfc3339_offset(b"-08:00")
+    );
+    assert_eq!(
+        Ok((
+            &[][..],
+            Offset::Fixed(UtcOffset::from_hms(5, 30, 0).unwrap())
+        )),
+        rfc3339_offset(b"+05:30")
     );
+    assert_eq!(
+        Ok((&[][..], Offset::Fixed(UtcOffset::UTC))),
+        rfc3339_offset(b"+00:00")
+    );
+    assert_eq!(Ok((&[][..], Offset::Unknown)), rfc3339_offset(b"-00:00"));
+    assert!(rfc3339_offset(b"+0530").is_err());
+    assert!(rfc3339_offset(b"+05").is_err());
+    assert!(rfc3339_offset(b"+24:00").is_err());
+    assert!(rfc3339_offset(b"+19:00").is_err());
+    assert!(rfc3339_offset(b"+05:30:15").is_ok_and(|(rest, _)| rest == b":15"));
+}
+
+#[test]
+fn test_parse_offset() {
+    let offset = |h, m, s| UtcOffset::from_hms(h, m, s).unwrap();
+    assert_eq!(Ok((&[][..], UtcOffset::UTC)), parse_offset(b"Z"));
+    assert_eq!(Ok((&[][..], UtcOffset::UTC)), parse_offset(b"-00:00"));
+    assert_eq!(Ok((&[][..], offset(5, 0, 0))), parse_offset(b"+05"));
+    assert_eq!(Ok((&[][..], offset(5, 30, 0))), parse_offset(b"+05:30"));
+    assert_eq!(Ok((&[][..], offset(-8, 0, 0))), parse_offset(b"-0800"));
+    assert_eq!(Ok((&[][..], offset(0, 17, 30))), parse_offset(b"+00:17:30"));
+    assert_eq!(Ok((&[][..], offset(0, -17, -30))), parse_offset(b"-001730"));
+    assert_eq!(Ok((&[][..], offset(18, 0, 0))), parse_offset(b"+18:00"));
+    assert_eq!(Ok((&[][..], offset(-18, 0, 0))), parse_offset(b"-18:00"));
+    // the separators have to be consistent
+    assert_eq!(
+        Ok((&b"30"[..], offset(0, 17, 0))),
+        parse_offset(b"+00:1730")
+    );
+    assert_eq!(
+        Ok((&b":30"[..], offset(0, 17, 0))),
+        parse_offset(b"+0017:30")
+    );
+
+    let err = parse_offset(b"+18:01").unwrap_err();
+    assert_eq!(
+        Err::Failure(Error {
+            input: &b"+18:01"[..],
+            component: Some(Component::Offset),
+            kind: ErrorKind::OutOfRange,
+        }),
+        err
+    );
+    assert!(parse_offset(b"+19").is_err());
+    // an invalid minute or second is left over
+    assert_eq!(Ok((&b":60"[..], offset(5, 0, 0))), parse_offset(b"+05:60"));
+    assert_eq!(
+        Ok((&b":60"[..], offset(5, 30, 0))),
+        parse_offset(b"+05:30:60")
+    );
+    assert!(parse_offset(b"05:30").is_err());
 }
 
 // #[test]
@@ -490,3 +881,46 @@ fn iso8601_vs_rfc3339() {
     // "2023-02-18_17:29:49.278Z".parse::<DateTime>().unwrap();
     // "2021-208T22:20:32.332320+08".parse::<DateTime>().unwrap();
 }
+
+#[test]
+fn test_parse_truncated_date() {
+    assert_eq!(
+        Ok((
+            &b"T10:00"[..],
+            TruncatedDate::MonthDay { month: 11, day: 2 }
+        )),
+        parse_truncated_date(b"--11-02T10:00")
+    );
+    assert_eq!(
+        Ok((
+            &[][..],
+            TruncatedDate::YearOfCentury {
+                yy: 15,
+                month: 11,
+                day: 2
+            }
+        )),
+        parse_truncated_date(b"15-1102")
+    );
+    // a month can't be followed by anything continuing the date
+    assert!(parse_truncated_date(b"--113").is_err());
+    assert!(parse_truncated_date(b"-W4").is_err());
+}
+
+#[test]
+fn test_time_hour_only() {
+    let hour = |hour, offset| Time {
+        hour,
+        offset,
+        precision: TimePrecision::Hour,
+        ..Time::default()
+    };
+    assert_eq!(Ok((&b"/"[..], hour(10, Offset::Local))), parse_time(b"10/"));
+    assert_eq!(Ok((&[][..], hour(10, Offset::Utc))), parse_time(b"T10Z"));
+    // a time of higher precision is preferred
+    assert_eq!(
+        Ok((&[][..], TimePrecision::Minute)),
+        parse_time(b"1030").map(|(rest, time)| (rest, time.precision))
+    );
+    assert!(parse_time(b"10T").is_err());
+}
@@ -0,0 +1,128 @@
+//! The [RFC 3339](https://www.rfc-editor.org/rfc/rfc3339#section-5.6) profile of ISO 8601.
+//!
+//! [`datetime()`][`crate::datetime()`] accepts many ISO 8601 formats RFC 3339 forbids,
+//! like week and ordinal dates, the basic format or a missing second or offset,
+//! and rejects some RFC 3339 allows, like a lowercase `t` and `z`
+//! or a space between date and time.
+//! The functions here implement exactly the grammar of RFC 3339 §5.6:
+//!
+//! * the date is `YYYY-MM-DD` and has to exist in the calendar
+//! * date and time are separated by `T`, `t` or a space
+//! * the time is `hh:mm:ss` with an optional fraction after a `.`, and hours only go up to 23
+//! * a second of 60 is only allowed for a leap second at the end of a UTC day
+//! * the offset is mandatory, either `Z`, `z` or `+hh:mm` and `-hh:mm`,
+//!   with `-00:00` for an unknown local offset, see [`Offset::Unknown`]
+//!
+//! ```
+//! use iso8601::rfc3339::{format_rfc3339, parse_rfc3339};
+//!
+//! let datetime = parse_rfc3339("1985-04-12t23:20:50.52z").unwrap();
+//! assert_eq!(format_rfc3339(&datetime).unwrap(), "1985-04-12T23:20:50.520Z");
+//!
+//! assert!(parse_rfc3339("1985-102T23:20:50Z").is_err());
+//! assert!(parse_rfc3339("1985-04-12T23:20Z").is_err());
+//! ```
+
+use alloc::string::{String, ToString};
+use core::fmt::{self, Display};
+
+use crate::{calendar, display::write_fraction, parsers, DateTime, Offset, ParseError, Time};
+
+/// Parses a datetime string following the RFC 3339 profile.
+///
+/// ## Example
+///
+/// ```rust
+/// let datetime = iso8601::rfc3339::parse_rfc3339("2015-06-26 16:43:16+02:00").unwrap();
+/// ```
+pub fn parse_rfc3339(string: &str) -> Result<DateTime, ParseError> {
+    ParseError::parse_all(string, parsers::parse_rfc3339)
+}
+
+/// Formats a datetime following the RFC 3339 profile, like `2015-06-26T16:43:16.123+02:00`.
+///
+/// Week and ordinal dates are written as calendar dates,
+/// and `24:00:00` as midnight of the next day.
+/// The fraction of a second is left out if it is zero.
+///
+/// The offset is written as it was parsed: `Z`, `-00:00` for an unknown offset,
+/// or like `+02:00`, including `+00:00`.
+///
+/// Returns `None` if the datetime can't be represented,
+/// i.e. if the date doesn't exist or is of reduced precision,
+/// the year is not within `0..=9999`, the time is out of range,
+/// the offset has seconds, or it is a local time without offset.
+///
+/// ## Example
+///
+/// ```rust
+/// use iso8601::rfc3339::format_rfc3339;
+///
+/// let datetime = iso8601::datetime("2015-W26-5T16:43:16Z").unwrap();
+/// assert_eq!(format_rfc3339(&datetime).unwrap(), "2015-06-26T16:43:16Z");
+/// ```
+pub fn format_rfc3339(datetime: &DateTime) -> Option<String> {
+    let Time {
+        hour,
+        minute,
+        second,
+        nanosecond,
+        offset,
+        ..
+    } = datetime.time;
+
+    let mut days = datetime.date.days()?;
+    let hour = match hour {
+        0..=23 => hour,
+        24 if minute == 0 && second == 0 && nanosecond == 0 => {
+            days += 1;
+            0
+        }
+        _ => return None,
+    };
+    if minute > 59 || second > 60 || nanosecond > 999_999_999 {
+        return None;
+    }
+    match offset {
+        Offset::Local => return None,
+        // RFC 3339 has no seconds in offsets
+        Offset::Fixed(offset) if offset.seconds() != 0 => return None,
+        _ => {}
+    }
+
+    let (year, month, day) = calendar::ymd_from_days(days)?;
+    if !(0..=9999).contains(&year) {
+        return None;
+    }
+
+    let formatted = Rfc3339 {
+        date: (year, month, day),
+        time: Time {
+            hour,
+            ..datetime.time
+        },
+    };
+    Some(formatted.to_string())
+}
+
+struct Rfc3339 {
+    date: (i32, u32, u32),
+    time: Time,
+}
+
+impl Display for Rfc3339 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (year, month, day) = self.date;
+        let time = &self.time;
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            year, month, day, time.hour, time.minute, time.second
+        )?;
+        if time.nanosecond > 0 {
+            write_fraction(f, time.nanosecond)?;
+        }
+
+        write!(f, "{}", time.offset)
+    }
+}
@@ -1,10 +1,11 @@
 use alloc::{fmt, string::ToString};
-use core::str::FromStr;
 use serde::{
     de::{self, Visitor},
     Deserialize, Deserializer, Serialize, Serializer,
 };
 
+use crate::ParseOptions;
+
 mod date {
 
     use super::*;
@@ -13,7 +14,7 @@ mod date {
         where
             D: Deserializer<'de>,
         {
-            deserializer.deserialize_str(DateVisitor)
+            deserializer.deserialize_str(DateVisitor(ParseOptions::new()))
         }
     }
 
@@ -26,7 +27,71 @@ mod date {
         }
     }
 
-    struct DateVisitor;
+    impl ParseOptions {
+        /// Deserializes a date with these options.
+        ///
+        /// The [`Deserialize`] implementations parse with the default options,
+        /// this can be wrapped in a function for serde's `deserialize_with` attribute instead.
+        ///
+        /// ```
+        /// fn validated_date<'de, D>(deserializer: D) -> Result<iso8601::Date, D::Error>
+        /// where
+        ///     D: serde::Deserializer<'de>,
+        /// {
+        ///     iso8601::ParseOptions::new().validate(true).deserialize_date(deserializer)
+        /// }
+        ///
+        /// let mut json = serde_json::Deserializer::from_str(r#""2015-02-29""#);
+        /// assert!(validated_date(&mut json).is_err());
+        /// ```
+        pub fn deserialize_date<'de, D>(&self, deserializer: D) -> Result<crate::Date, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(DateVisitor(*self))
+        }
+
+        /// Serializes a date with years in the expanded representation these options expect,
+        /// see [`ParseOptions::expanded_year`], and like the [`Serialize`] implementation otherwise.
+        ///
+        /// Along with [`ParseOptions::deserialize_date`], this can be wrapped in functions
+        /// for serde's `serialize_with` and `deserialize_with` attributes.
+        ///
+        /// ```
+        /// use iso8601::{Date, ParseOptions};
+        ///
+        /// const EXPANDED: ParseOptions = ParseOptions::new().expanded_year(2);
+        ///
+        /// fn serialize<S: serde::Serializer>(date: &Date, serializer: S) -> Result<S::Ok, S::Error> {
+        ///     EXPANDED.serialize_date(date, serializer)
+        /// }
+        ///
+        /// let date = Date::YMD { year: 12345, month: 1, day: 1 };
+        /// let mut json = Vec::new();
+        /// serialize(&date, &mut serde_json::Serializer::new(&mut json)).unwrap();
+        /// assert_eq!(json, br#""+012345-01-01""#);
+        ///
+        /// let mut json = serde_json::Deserializer::from_slice(&json);
+        /// assert_eq!(EXPANDED.deserialize_date(&mut json).unwrap(), date);
+        /// ```
+        pub fn serialize_date<S>(
+            &self,
+            date: &crate::Date,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self.expanded_year_digits {
+                Some(extra_digits) => {
+                    serializer.serialize_str(&date.display_expanded(extra_digits).to_string())
+                }
+                None => date.serialize(serializer),
+            }
+        }
+    }
+
+    struct DateVisitor(ParseOptions);
     impl<'de> Visitor<'de> for DateVisitor {
         type Value = crate::Date;
 
@@ -38,7 +103,7 @@ mod date {
         where
             E: de::Error,
         {
-            match Self::Value::from_str(s) {
+            match self.0.date(s) {
                 Ok(p) => Ok(p),
                 Err(_) => Err(de::Error::invalid_value(de::Unexpected::Str(s), &self)),
             }
@@ -105,6 +170,70 @@ mod date {
         assert_eq!(deserialized_date, date);
     }
 
+    #[test]
+    fn serialize_reduced_precision() {
+        for date_json in [r#""2023-02""#, r#""2023-W06""#, r#""2023""#, r#""20""#] {
+            let date = crate::date(date_json.trim_matches('"')).unwrap();
+
+            let serialized_date = serde_json::to_string(&date).unwrap();
+
+            assert_eq!(serialized_date, date_json);
+        }
+    }
+
+    #[test]
+    fn deserialize_reduced_precision() {
+        let date_json = r#""2023-02""#;
+        let date = crate::Date::YearMonth {
+            year: 2023,
+            month: 2,
+        };
+
+        let deserialized_date = serde_json::from_str::<crate::Date>(date_json).unwrap();
+
+        assert_eq!(deserialized_date, date);
+    }
+
+    #[test]
+    fn roundtrip_expanded_year() {
+        let date_json = r#""+12345-01-01""#;
+
+        let deserialized_date = serde_json::from_str::<crate::Date>(date_json).unwrap();
+        let serialized_date = serde_json::to_string(&deserialized_date).unwrap();
+
+        assert_eq!(serialized_date, date_json);
+    }
+
+    #[test]
+    fn roundtrip_expanded_century() {
+        let date_json = r#""+123""#;
+
+        let deserialized_date = serde_json::from_str::<crate::Date>(date_json).unwrap();
+        let serialized_date = serde_json::to_string(&deserialized_date).unwrap();
+
+        assert_eq!(deserialized_date, crate::Date::Century { century: 123 });
+        assert_eq!(serialized_date, date_json);
+    }
+
+    #[test]
+    fn roundtrip_agreed_expanded_year() {
+        let options = ParseOptions::new().expanded_year(3);
+        for date_json in [r#""+0002015-11-02""#, r#""-1234567-W01-1""#, r#""+12345""#] {
+            let mut deserializer = serde_json::Deserializer::from_str(date_json);
+            let date = options.deserialize_date(&mut deserializer).unwrap();
+
+            let mut serialized_date = alloc::vec::Vec::new();
+            options
+                .serialize_date(
+                    &date,
+                    &mut serde_json::Serializer::new(&mut serialized_date),
+                )
+                .unwrap();
+
+            assert_eq!(serialized_date, date_json.as_bytes());
+        }
+    }
+
     #[test]
     fn serialize_negative_year() {
         let date_json = r#""-0333-07-11""#;
@@ -134,7 +263,7 @@ mod time {
         where
             D: Deserializer<'de>,
         {
-            deserializer.deserialize_str(TimeVisitor)
+            deserializer.deserialize_str(TimeVisitor(ParseOptions::new()))
         }
     }
 
@@ -147,7 +276,18 @@ mod time {
         }
     }
 
-    struct TimeVisitor;
+    impl ParseOptions {
+        /// Deserializes a time with these options,
+        /// see [`ParseOptions::deserialize_date`] for how to use it with serde's `deserialize_with`.
+        pub fn deserialize_time<'de, D>(&self, deserializer: D) -> Result<crate::Time, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(TimeVisitor(*self))
+        }
+    }
+
+    struct TimeVisitor(ParseOptions);
     impl<'de> Visitor<'de> for TimeVisitor {
         type Value = crate::Time;
 
@@ -159,7 +299,7 @@ mod time {
         where
             E: de::Error,
         {
-            match Self::Value::from_str(s) {
+            match self.0.time(s) {
                 Ok(p) => Ok(p),
                 Err(_) => Err(de::Error::invalid_value(de::Unexpected::Str(s), &self)),
             }
@@ -187,6 +327,66 @@ mod time {
     }
 }
 
+mod utc_offset {
+    use super::*;
+    impl<'de> Deserialize<'de> for crate::UtcOffset {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(UtcOffsetVisitor)
+        }
+    }
+
+    impl Serialize for crate::UtcOffset {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(&self.to_string())
+        }
+    }
+
+    struct UtcOffsetVisitor;
+    impl<'de> Visitor<'de> for UtcOffsetVisitor {
+        type Value = crate::UtcOffset;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a string that follows iso8601 offset format")
+        }
+
+        fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match s.parse() {
+                Ok(p) => Ok(p),
+                Err(_) => Err(de::Error::invalid_value(de::Unexpected::Str(s), &self)),
+            }
+        }
+    }
+
+    #[test]
+    fn serialize() {
+        let offset = crate::UtcOffset::from_hms(-5, -30, 0).unwrap();
+
+        let serialized_offset = serde_json::to_string(&offset).unwrap();
+
+        assert_eq!(serialized_offset, r#""-05:30""#);
+    }
+
+    #[test]
+    fn deserialize() {
+        let offset = crate::UtcOffset::from_hms(0, 17, 30).unwrap();
+
+        let deserialized_offset =
+            serde_json::from_str::<crate::UtcOffset>(r#""+00:17:30""#).unwrap();
+
+        assert_eq!(deserialized_offset, offset);
+        assert!(serde_json::from_str::<crate::UtcOffset>(r#""+19:00""#).is_err());
+    }
+}
+
 mod datetime {
     use super::*;
     impl<'de> Deserialize<'de> for crate::DateTime {
@@ -194,7 +394,7 @@ mod datetime {
         where
             D: Deserializer<'de>,
         {
-            deserializer.deserialize_str(DateTimeVisitor)
+            deserializer.deserialize_str(DateTimeVisitor(ParseOptions::new()))
         }
     }
 
@@ -207,7 +407,39 @@ mod datetime {
         }
     }
 
-    struct DateTimeVisitor;
+    impl ParseOptions {
+        /// Deserializes a datetime with these options,
+        /// see [`ParseOptions::deserialize_date`] for how to use it with serde's `deserialize_with`.
+        pub fn deserialize_datetime<'de, D>(
+            &self,
+            deserializer: D,
+        ) -> Result<crate::DateTime, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(DateTimeVisitor(*self))
+        }
+
+        /// Serializes a datetime with years in the expanded representation these options expect,
+        /// see [`ParseOptions::serialize_date`] for how to use it with serde's `serialize_with`.
+        pub fn serialize_datetime<S>(
+            &self,
+            datetime: &crate::DateTime,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match self.expanded_year_digits {
+                Some(extra_digits) => {
+                    serializer.serialize_str(&datetime.display_expanded(extra_digits).to_string())
+                }
+                None => datetime.serialize(serializer),
+            }
+        }
+    }
+
+    struct DateTimeVisitor(ParseOptions);
     impl<'de> Visitor<'de> for DateTimeVisitor {
         type Value = crate::DateTime;
 
@@ -219,7 +451,7 @@ mod datetime {
         where
             E: de::Error,
         {
-            match Self::Value::from_str(s) {
+            match self.0.datetime(s) {
                 Ok(p) => Ok(p),
                 Err(_) => Err(de::Error::invalid_value(de::Unexpected::Str(s), &self)),
             }
@@ -255,6 +487,40 @@ mod datetime {
 
         assert_eq!(deserialized_datetime, datetime);
     }
+
+    #[test]
+    fn roundtrip_agreed_expanded_year() {
+        let options = ParseOptions::new().expanded_year(1);
+        let datetime_json = r#""+12015-02-10T18:12Z""#;
+
+        let mut deserializer = serde_json::Deserializer::from_str(datetime_json);
+        let datetime = options.deserialize_datetime(&mut deserializer).unwrap();
+
+        let mut serialized_datetime = alloc::vec::Vec::new();
+        options
+            .serialize_datetime(
+                &datetime,
+                &mut serde_json::Serializer::new(&mut serialized_datetime),
+            )
+            .unwrap();
+
+        assert_eq!(serialized_datetime, datetime_json.as_bytes());
+    }
+
+    #[test]
+    fn deserialize_with_options() {
+        let datetime_json = r#""2023-02-10 18:12:15z""#;
+        let datetime = crate::datetime("2023-02-10T18:12:15Z").unwrap();
+        let options = ParseOptions::new()
+            .space_separator(true)
+            .case_insensitive(true);
+
+        assert!(serde_json::from_str::<crate::DateTime>(datetime_json).is_err());
+        let mut deserializer = serde_json::Deserializer::from_str(datetime_json);
+        let deserialized_datetime = options.deserialize_datetime(&mut deserializer).unwrap();
+
+        assert_eq!(deserialized_datetime, datetime);
+    }
 }
 
 mod duration {
@@ -265,7 +531,7 @@ mod duration {
         where
             D: Deserializer<'de>,
         {
-            deserializer.deserialize_str(DurationVisitor)
+            deserializer.deserialize_str(DurationVisitor(ParseOptions::new()))
         }
     }
 
@@ -278,7 +544,21 @@ mod duration {
         }
     }
 
-    struct DurationVisitor;
+    impl ParseOptions {
+        /// Deserializes a duration with these options,
+        /// see [`ParseOptions::deserialize_date`] for how to use it with serde's `deserialize_with`.
+        pub fn deserialize_duration<'de, D>(
+            &self,
+            deserializer: D,
+        ) -> Result<crate::Duration, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(DurationVisitor(*self))
+        }
+    }
+
+    struct DurationVisitor(ParseOptions);
     impl<'de> Visitor<'de> for DurationVisitor {
         type Value = crate::Duration;
 
@@ -290,7 +570,7 @@ mod duration {
         where
             E: de::Error,
         {
-            match Self::Value::from_str(s) {
+            match self.0.duration(s) {
                 Ok(p) => Ok(p),
                 Err(_) => Err(de::Error::invalid_value(de::Unexpected::Str(s), &self)),
             }
@@ -336,4 +616,180 @@ mod duration {
 
         assert_eq!(deserialized_duration, duration);
     }
+
+    #[test]
+    fn negative() {
+        let duration_json = r#""-P1DT0.500S""#;
+        let duration = crate::duration("P-1DT-0.5S").unwrap();
+
+        assert_eq!(serde_json::to_string(&duration).unwrap(), duration_json);
+        let deserialized_duration = serde_json::from_str::<crate::Duration>(duration_json).unwrap();
+        assert_eq!(deserialized_duration, duration);
+    }
+
+    #[test]
+    fn deserialize_with_options() {
+        let duration_json = r#""p1y2m3dt4h5m6s""#;
+        let duration = crate::duration("P1Y2M3DT4H5M6S").unwrap();
+        let options = ParseOptions::new().case_insensitive(true);
+
+        let mut deserializer = serde_json::Deserializer::from_str(duration_json);
+        let deserialized_duration = options.deserialize_duration(&mut deserializer).unwrap();
+
+        assert_eq!(deserialized_duration, duration);
+    }
+}
+
+mod interval {
+    use super::*;
+
+    impl<'de> Deserialize<'de> for crate::Interval {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(IntervalVisitor(ParseOptions::new()))
+        }
+    }
+
+    impl Serialize for crate::Interval {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(&self.to_string())
+        }
+    }
+
+    impl ParseOptions {
+        /// Deserializes a time interval with these options,
+        /// see [`ParseOptions::deserialize_date`] for how to use it with serde's `deserialize_with`.
+        pub fn deserialize_interval<'de, D>(
+            &self,
+            deserializer: D,
+        ) -> Result<crate::Interval, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(IntervalVisitor(*self))
+        }
+    }
+
+    struct IntervalVisitor(ParseOptions);
+    impl<'de> Visitor<'de> for IntervalVisitor {
+        type Value = crate::Interval;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a string that follows iso8601 Interval format")
+        }
+
+        fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match self.0.interval(s) {
+                Ok(p) => Ok(p),
+                Err(_) => Err(de::Error::invalid_value(de::Unexpected::Str(s), &self)),
+            }
+        }
+    }
+
+    #[test]
+    fn serialize() {
+        let interval_json = r#""2023-02-10/P1Y2M3DT4H5M6S""#;
+        let interval = crate::interval("2023-02-10/P1Y2M3DT4H5M6S").unwrap();
+
+        let serialized_interval = serde_json::to_string(&interval).unwrap();
+
+        assert_eq!(serialized_interval, interval_json);
+    }
+
+    #[test]
+    fn deserialize() {
+        let interval_json = r#""2023-02-10/03-14""#;
+        let interval = crate::interval("2023-02-10/2023-03-14").unwrap();
+
+        let deserialized_interval = serde_json::from_str::<crate::Interval>(interval_json).unwrap();
+
+        assert_eq!(deserialized_interval, interval);
+    }
+}
+
+mod repeating_interval {
+    use super::*;
+
+    impl<'de> Deserialize<'de> for crate::RepeatingInterval {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(RepeatingIntervalVisitor(ParseOptions::new()))
+        }
+    }
+
+    impl Serialize for crate::RepeatingInterval {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.serialize_str(&self.to_string())
+        }
+    }
+
+    impl ParseOptions {
+        /// Deserializes a recurring time interval with these options,
+        /// see [`ParseOptions::deserialize_date`] for how to use it with serde's `deserialize_with`.
+        pub fn deserialize_repeating_interval<'de, D>(
+            &self,
+            deserializer: D,
+        ) -> Result<crate::RepeatingInterval, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_str(RepeatingIntervalVisitor(*self))
+        }
+    }
+
+    struct RepeatingIntervalVisitor(ParseOptions);
+    impl<'de> Visitor<'de> for RepeatingIntervalVisitor {
+        type Value = crate::RepeatingInterval;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(
+                formatter,
+                "a string that follows iso8601 repeating Interval format"
+            )
+        }
+
+        fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match self.0.repeating_interval(s) {
+                Ok(p) => Ok(p),
+                Err(_) => Err(de::Error::invalid_value(de::Unexpected::Str(s), &self)),
+            }
+        }
+    }
+
+    #[test]
+    fn serialize() {
+        let repeating_json = r#""R5/2023-02-10/P1D""#;
+        let repeating = crate::repeating_interval("R5/2023-02-10/P1D").unwrap();
+
+        let serialized_repeating = serde_json::to_string(&repeating).unwrap();
+
+        assert_eq!(serialized_repeating, repeating_json);
+    }
+
+    #[test]
+    fn deserialize() {
+        let repeating_json = r#""R/P1W/2023-02-10""#;
+        let repeating = crate::repeating_interval("R/P1W/2023-02-10").unwrap();
+
+        let deserialized_repeating =
+            serde_json::from_str::<crate::RepeatingInterval>(repeating_json).unwrap();
+
+        assert_eq!(deserialized_repeating, repeating);
+    }
 }
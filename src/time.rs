@@ -1,17 +1,26 @@
-use alloc::string::String;
 use core::str::FromStr;
 
-use crate::parsers;
+use crate::{parsers, Offset, ParseError, UtcOffset};
 
 /// A time object.
 /// ```
 /// # use std::str::FromStr;
 /// assert_eq!(
 ///     iso8601::Time::from_str("17:08:08.793Z"),
-///     Ok(iso8601::Time{ hour: 17, minute: 8, second: 8, millisecond: 793, tz_offset_hours: 0, tz_offset_minutes: 00 })
+///     Ok(iso8601::Time {
+///         hour: 17,
+///         minute: 8,
+///         second: 8,
+///         nanosecond: 793_000_000,
+///         offset: iso8601::Offset::Utc,
+///         precision: iso8601::TimePrecision::Second,
+///     })
 /// )
 /// ```
-#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+///
+/// Times are equal if their components and offsets are,
+/// whatever their [`precision`][`Time::precision`]: `10:30` equals `10:30:00`.
+#[derive(Eq, Debug, Copy, Clone, Default)]
 pub struct Time {
     /// a 24th of a day
     pub hour: u32,
@@ -19,12 +28,46 @@ pub struct Time {
     pub minute: u32,
     /// a minute are 60 of these
     pub second: u32,
-    /// everything after a `.`
-    pub millisecond: u32,
-    /// the hour part of the timezone offset from UTC
-    pub tz_offset_hours: i32,
-    /// the minute part of the timezone offset from UTC
-    pub tz_offset_minutes: i32,
+    /// everything after a `.`, in nanoseconds
+    pub nanosecond: u32,
+    /// the timezone offset, if any
+    pub offset: Offset,
+    /// the lowest-order component the time was written with
+    pub precision: TimePrecision,
+}
+
+/// The lowest-order component of a [`Time`], which it is displayed down to.
+///
+/// A time is always displayed with the components it needs,
+/// e.g. `10:30` even with a precision of [`TimePrecision::Hour`].
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Default)]
+pub enum TimePrecision {
+    /// hours only, like `10`
+    Hour,
+    /// hours and minutes, like `10:30`
+    Minute,
+    /// hours, minutes, seconds and the fraction of the second, like `10:30:00.000`
+    #[default]
+    Second,
+}
+
+// The precision is how a time was written, not when it is.
+impl PartialEq for Time {
+    fn eq(&self, other: &Time) -> bool {
+        (
+            self.hour,
+            self.minute,
+            self.second,
+            self.nanosecond,
+            self.offset,
+        ) == (
+            other.hour,
+            other.minute,
+            other.second,
+            other.nanosecond,
+            other.offset,
+        )
+    }
 }
 
 impl Time {
@@ -32,17 +75,16 @@ impl Time {
     ///
     /// # Arguments
     ///
-    /// * `tzo` - A tuple of `(hours, minutes)` specifying the timezone offset from UTC.
-    pub fn set_tz(&self, tzo: (i32, i32)) -> Time {
+    /// * `offset` - The timezone offset from UTC.
+    pub fn set_tz(&self, offset: UtcOffset) -> Time {
         let mut t = *self;
-        t.tz_offset_hours = tzo.0;
-        t.tz_offset_minutes = tzo.1;
+        t.offset = Offset::Fixed(offset);
         t
     }
 }
 
 impl FromStr for Time {
-    type Err = String;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         time(s)
@@ -54,19 +96,36 @@ impl FromStr for Time {
 /// A string can have one of the following formats:
 ///
 /// * `07:35:[00][.123]` or `0735[00][.123]`
+/// * `07:35:[00][.123456789]`, up to nanosecond precision
 /// * `07:35:[00][.123][(Z|(+|-)00:00)]`
 /// * `0735[00][.123][(Z|(+|-)00:00)]`
 /// * `0735[00][.123][(Z|(+|-)0000)]`
+/// * `07[(Z|(+|-)00:00)]`, the hour only
+///
+/// and be prefixed by a `T`, like `T0735` or `T07`, to tell it apart from a date.
+/// The lowest-order component is recorded in [`Time::precision`],
+/// and the time is displayed down to it.
+///
+/// The offset may have seconds, like `+00:17:30`, and is at most 18 hours
+/// in either direction, see [`UtcOffset`].
+/// A time without offset is a local time, see [`Offset`].
+///
+/// The end of the day is `24:00:00`, which can't have minutes, seconds or a fraction,
+/// see [`DateTime::normalize`][`crate::DateTime::normalize`].
+///
+/// The lowest-order component may have a decimal fraction,
+/// which is spread over the lower fields, for a precision of [`TimePrecision::Second`]:
+///
+/// * `07:35.5` or `0735.5` is `07:35:30`
+/// * `07.25` is `07:15:00`
 ///
 /// ## Example
 ///
 /// ```rust
 /// let time = iso8601::time("21:56:42").unwrap();
+/// let hour = iso8601::time("T21").unwrap();
+/// assert_eq!(hour.to_string(), "21");
 /// ```
-pub fn time(string: &str) -> Result<Time, String> {
-    if let Ok((_, parsed)) = parsers::parse_time(string.as_bytes()) {
-        Ok(parsed)
-    } else {
-        Err(format!("Failed to parse time: {}", string))
-    }
+pub fn time(string: &str) -> Result<Time, ParseError> {
+    ParseError::parse_all(string, parsers::parse_time)
 }
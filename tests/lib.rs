@@ -30,9 +30,9 @@ fn test_millisecond() {
                 hour: 16,
                 minute: 43,
                 second: 0,
-                millisecond: i,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
+                nanosecond: i * 1_000_000,
+                offset: Offset::Local,
+                precision: TimePrecision::Second
             }),
             time(format!("16:43:00.{:0>3}", i).as_str())
         );
@@ -43,9 +43,9 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 42,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 42_000_000,
+            offset: Offset::Local,
+            precision: TimePrecision::Second
         }),
         time("16:43:00.042")
     );
@@ -54,9 +54,9 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 10,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 10_000_000,
+            offset: Offset::Local,
+            precision: TimePrecision::Second
         }),
         time("16:43:00.01")
     );
@@ -65,9 +65,9 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 100,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 100_000_000,
+            offset: Offset::Local,
+            precision: TimePrecision::Second
         }),
         time("16:43:00.1")
     );
@@ -76,9 +76,9 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 120,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 120_000_000,
+            offset: Offset::Local,
+            precision: TimePrecision::Second
         }),
         time("16:43:00.12")
     );
@@ -87,9 +87,9 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 123,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 123_000_000,
+            offset: Offset::Local,
+            precision: TimePrecision::Second
         }),
         time("16:43:00.123")
     );
@@ -98,9 +98,9 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 432,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 432_100_000,
+            offset: Offset::Local,
+            precision: TimePrecision::Second
         }),
         time("16:43:00.4321")
     );
@@ -108,10 +108,10 @@ fn test_millisecond() {
         Ok(Time {
             hour: 16,
             minute: 43,
-            second: 0,
-            millisecond: 432,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            second: 25,
+            nanosecond: 926_000_000,
+            offset: Offset::Local,
+            precision: TimePrecision::Second
         }),
         time("16:43.4321")
     );
@@ -120,9 +120,9 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 11,
-            millisecond: 432,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 432_100_000,
+            offset: Offset::Local,
+            precision: TimePrecision::Second
         }),
         time("16:43:11.4321")
     );
@@ -132,9 +132,9 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 100,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 100_000_000,
+            offset: Offset::Local,
+            precision: TimePrecision::Second
         }),
         time("16:43:00,1")
     );
@@ -144,9 +144,9 @@ fn test_millisecond() {
             hour: 4,
             minute: 5,
             second: 6,
-            millisecond: 123,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 123_450_000,
+            offset: Offset::Local,
+            precision: TimePrecision::Second
         }),
         time("04:05:06.12345")
     );
@@ -162,9 +162,9 @@ fn test_millisecond() {
                 hour: 4,
                 minute: 5,
                 second: 6,
-                millisecond: 123,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
+                nanosecond: 123_450_000,
+                offset: Offset::Utc,
+                precision: TimePrecision::Second
             }
         }),
         datetime("2001-W05-6T04:05:06.12345Z")
@@ -175,9 +175,9 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 123,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 123_000_000,
+            offset: Offset::Local,
+            precision: TimePrecision::Second
         }),
         time("16:43:16.123")
     );
@@ -186,9 +186,9 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 123,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 123_000_000,
+            offset: Offset::Fixed(UtcOffset::UTC),
+            precision: TimePrecision::Second
         }),
         time("16:43:16.123+00:00")
     );
@@ -197,9 +197,9 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 123,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 123_000_000,
+            offset: Offset::Unknown,
+            precision: TimePrecision::Second
         }),
         time("16:43:16.123-00:00")
     );
@@ -208,9 +208,9 @@ fn test_millisecond() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 123,
-            tz_offset_hours: 5,
-            tz_offset_minutes: 0
+            nanosecond: 123_000_000,
+            offset: Offset::Fixed(UtcOffset::from_hms(5, 0, 0).unwrap()),
+            precision: TimePrecision::Second
         }),
         time("16:43:16.123+05:00")
     );
@@ -224,9 +224,9 @@ fn test_time() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            offset: Offset::Local,
+            precision: TimePrecision::Second,
         })
     );
     assert_eq!(
@@ -235,9 +235,9 @@ fn test_time() {
             hour: 16,
             minute: 43,
             second: 0,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            offset: Offset::Local,
+            precision: TimePrecision::Minute,
         })
     );
 
@@ -252,20 +252,23 @@ fn test_time_set_tz() {
         hour: 0,
         minute: 0,
         second: 0,
-        millisecond: 0,
-        tz_offset_hours: 0,
-        tz_offset_minutes: 0,
+        nanosecond: 0,
+        offset: Offset::Local,
+        precision: TimePrecision::Second,
     };
     let expected = Time {
         hour: 0,
         minute: 0,
         second: 0,
-        millisecond: 0,
-        tz_offset_hours: 2,
-        tz_offset_minutes: 30,
+        nanosecond: 0,
+        offset: Offset::Fixed(UtcOffset::from_hms(2, 30, 0).unwrap()),
+        precision: TimePrecision::Second,
     };
 
-    assert_eq!(expected, original.set_tz((2, 30)));
+    assert_eq!(
+        expected,
+        original.set_tz(UtcOffset::from_hms(2, 30, 0).unwrap())
+    );
 }
 
 #[test]
@@ -276,9 +279,9 @@ fn short_time1() {
             hour: 16,
             minute: 48,
             second: 0,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            offset: Offset::Local,
+            precision: TimePrecision::Minute,
         })
     );
 }
@@ -290,9 +293,9 @@ fn short_time2() {
             hour: 16,
             minute: 48,
             second: 0,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            offset: Offset::Local,
+            precision: TimePrecision::Minute,
         })
     );
 }
@@ -304,9 +307,9 @@ fn short_time3() {
             hour: 16,
             minute: 48,
             second: 0,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            offset: Offset::Utc,
+            precision: TimePrecision::Minute,
         })
     );
 }
@@ -318,9 +321,9 @@ fn short_time4() {
             hour: 16,
             minute: 48,
             second: 0,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            offset: Offset::Local,
+            precision: TimePrecision::Second,
         })
     );
 }
@@ -332,9 +335,9 @@ fn short_time5() {
             hour: 16,
             minute: 48,
             second: 0,
-            millisecond: 100,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 100_000_000,
+            offset: Offset::Local,
+            precision: TimePrecision::Second,
         })
     );
 }
@@ -346,9 +349,9 @@ fn short_time6() {
             hour: 16,
             minute: 48,
             second: 0,
-            millisecond: 100,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 100_000_000,
+            offset: Offset::Utc,
+            precision: TimePrecision::Second,
         })
     );
 }
@@ -360,9 +363,9 @@ fn short_time7() {
             hour: 16,
             minute: 48,
             second: 0,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            offset: Offset::Local,
+            precision: TimePrecision::Second,
         })
     );
 }
@@ -375,9 +378,9 @@ fn short_twtz1() {
             hour: 16,
             minute: 48,
             second: 0,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            offset: Offset::Utc,
+            precision: TimePrecision::Minute,
         })
     );
 }
@@ -389,9 +392,9 @@ fn short_twtz2() {
             hour: 16,
             minute: 48,
             second: 0,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            offset: Offset::Utc,
+            precision: TimePrecision::Minute,
         })
     );
 }
@@ -410,9 +413,9 @@ fn short_dtim1() {
                 hour: 16,
                 minute: 48,
                 second: 0,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0,
+                nanosecond: 0,
+                offset: Offset::Local,
+                precision: TimePrecision::Minute,
             }
         })
     );
@@ -431,9 +434,9 @@ fn short_dtim2() {
                 hour: 16,
                 minute: 48,
                 second: 0,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0,
+                nanosecond: 0,
+                offset: Offset::Utc,
+                precision: TimePrecision::Minute,
             },
         })
     );
@@ -452,9 +455,9 @@ fn short_dtim3() {
                 hour: 18,
                 minute: 21,
                 second: 0,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0,
+                nanosecond: 0,
+                offset: Offset::Utc,
+                precision: TimePrecision::Minute,
             },
         })
     );
@@ -467,9 +470,9 @@ fn test_time_with_timezone() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            offset: Offset::Local,
+            precision: TimePrecision::Second,
         }),
         time("16:43:16")
     );
@@ -478,9 +481,9 @@ fn test_time_with_timezone() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            offset: Offset::Utc,
+            precision: TimePrecision::Second,
         }),
         time("16:43:16Z")
     );
@@ -489,9 +492,9 @@ fn test_time_with_timezone() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            offset: Offset::Fixed(UtcOffset::UTC),
+            precision: TimePrecision::Second,
         }),
         time("16:43:16+00:00")
     );
@@ -500,9 +503,9 @@ fn test_time_with_timezone() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            offset: Offset::Unknown,
+            precision: TimePrecision::Second,
         }),
         time("16:43:16-00:00")
     );
@@ -511,9 +514,9 @@ fn test_time_with_timezone() {
             hour: 16,
             minute: 43,
             second: 16,
-            millisecond: 0,
-            tz_offset_hours: 5,
-            tz_offset_minutes: 0,
+            nanosecond: 0,
+            offset: Offset::Fixed(UtcOffset::from_hms(5, 0, 0).unwrap()),
+            precision: TimePrecision::Second,
         }),
         time("16:43:16+05:00")
     );
@@ -627,6 +630,10 @@ fn format_equivalence() {
         datetime("2001-02-03T040506+0700")
     );
     assert_eq!(
+        datetime("20010203T040506+0000"),
+        datetime("2001-02-03T04:05:06+00:00")
+    );
+    assert_ne!(
         datetime("20010203T040506+0000"),
         datetime("20010203T040506Z")
     );
@@ -650,9 +657,9 @@ fn test_datetime_correct() {
                 hour: 16,
                 minute: 44,
                 second: 0,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
+                nanosecond: 0,
+                offset: Offset::Fixed(UtcOffset::UTC),
+                precision: TimePrecision::Minute
             }
         })
     );
@@ -668,9 +675,9 @@ fn test_datetime_correct() {
                 hour: 16,
                 minute: 45,
                 second: 0,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
+                nanosecond: 0,
+                offset: Offset::Fixed(UtcOffset::UTC),
+                precision: TimePrecision::Minute
             }
         })
     );
@@ -686,9 +693,9 @@ fn test_datetime_correct() {
                 hour: 16,
                 minute: 46,
                 second: 0,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
+                nanosecond: 0,
+                offset: Offset::Fixed(UtcOffset::UTC),
+                precision: TimePrecision::Minute
             }
         })
     );
@@ -704,9 +711,9 @@ fn test_datetime_correct() {
                 hour: 16,
                 minute: 47,
                 second: 0,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
+                nanosecond: 0,
+                offset: Offset::Fixed(UtcOffset::UTC),
+                precision: TimePrecision::Minute
             }
         })
     );
@@ -722,9 +729,9 @@ fn test_datetime_correct() {
                 hour: 9,
                 minute: 0,
                 second: 22,
-                millisecond: 0,
-                tz_offset_hours: 5,
-                tz_offset_minutes: 0
+                nanosecond: 0,
+                offset: Offset::Fixed(UtcOffset::from_hms(5, 0, 0).unwrap()),
+                precision: TimePrecision::Second
             }
         })
     );
@@ -740,9 +747,9 @@ fn test_datetime_correct() {
                 hour: 12,
                 minute: 0,
                 second: 0,
-                millisecond: 0,
-                tz_offset_hours: 1,
-                tz_offset_minutes: 0
+                nanosecond: 0,
+                offset: Offset::Fixed(UtcOffset::from_hms(1, 0, 0).unwrap()),
+                precision: TimePrecision::Second
             }
         })
     );
@@ -758,9 +765,9 @@ fn test_datetime_correct() {
                 hour: 18,
                 minute: 30,
                 second: 0,
-                millisecond: 0,
-                tz_offset_hours: 2,
-                tz_offset_minutes: 0
+                nanosecond: 0,
+                offset: Offset::Fixed(UtcOffset::from_hms(2, 0, 0).unwrap()),
+                precision: TimePrecision::Second
             }
         })
     );
@@ -776,9 +783,9 @@ fn test_datetime_correct() {
                 hour: 23,
                 minute: 7,
                 second: 0,
-                millisecond: 0,
-                tz_offset_hours: 2,
-                tz_offset_minutes: 0
+                nanosecond: 0,
+                offset: Offset::Fixed(UtcOffset::from_hms(2, 0, 0).unwrap()),
+                precision: TimePrecision::Minute
             }
         })
     );
@@ -794,9 +801,9 @@ fn test_datetime_correct() {
                 hour: 16,
                 minute: 43,
                 second: 16,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
+                nanosecond: 0,
+                offset: Offset::Local,
+                precision: TimePrecision::Second
             }
         })
     );
@@ -812,9 +819,9 @@ fn test_datetime_correct() {
                 hour: 16,
                 minute: 43,
                 second: 16,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
+                nanosecond: 0,
+                offset: Offset::Local,
+                precision: TimePrecision::Second
             }
         })
     );
@@ -830,9 +837,9 @@ fn test_datetime_correct() {
                 hour: 4,
                 minute: 5,
                 second: 6,
-                millisecond: 0,
-                tz_offset_hours: 7,
-                tz_offset_minutes: 0
+                nanosecond: 0,
+                offset: Offset::Fixed(UtcOffset::from_hms(7, 0, 0).unwrap()),
+                precision: TimePrecision::Second
             }
         })
     );
@@ -848,9 +855,9 @@ fn test_datetime_correct() {
                 hour: 4,
                 minute: 5,
                 second: 6,
-                millisecond: 0,
-                tz_offset_hours: 7,
-                tz_offset_minutes: 0
+                nanosecond: 0,
+                offset: Offset::Fixed(UtcOffset::from_hms(7, 0, 0).unwrap()),
+                precision: TimePrecision::Second
             }
         })
     );
@@ -865,9 +872,9 @@ fn test_datetime_correct() {
                 hour: 4,
                 minute: 5,
                 second: 6,
-                millisecond: 0,
-                tz_offset_hours: 7,
-                tz_offset_minutes: 0
+                nanosecond: 0,
+                offset: Offset::Fixed(UtcOffset::from_hms(7, 0, 0).unwrap()),
+                precision: TimePrecision::Second
             }
         })
     );
@@ -882,9 +889,9 @@ fn test_datetime_correct() {
                 hour: 4,
                 minute: 5,
                 second: 6,
-                millisecond: 0,
-                tz_offset_hours: 7,
-                tz_offset_minutes: 0
+                nanosecond: 0,
+                offset: Offset::Fixed(UtcOffset::from_hms(7, 0, 0).unwrap()),
+                precision: TimePrecision::Second
             }
         })
     );
@@ -899,9 +906,9 @@ fn test_datetime_correct() {
                 hour: 16,
                 minute: 30,
                 second: 48,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
+                nanosecond: 0,
+                offset: Offset::Utc,
+                precision: TimePrecision::Second
             }
         })
     );
@@ -917,9 +924,9 @@ fn test_datetime_correct() {
                 hour: 16,
                 minute: 30,
                 second: 48,
-                millisecond: 0,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
+                nanosecond: 0,
+                offset: Offset::Utc,
+                precision: TimePrecision::Second
             }
         })
     );
@@ -935,9 +942,9 @@ fn test_datetime_correct() {
                 hour: 4,
                 minute: 5,
                 second: 6,
-                millisecond: 123,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
+                nanosecond: 123_400_000,
+                offset: Offset::Utc,
+                precision: TimePrecision::Second
             }
         })
     );
@@ -953,9 +960,9 @@ fn test_datetime_correct() {
                 hour: 4,
                 minute: 5,
                 second: 6,
-                millisecond: 123,
-                tz_offset_hours: 0,
-                tz_offset_minutes: 0
+                nanosecond: 123_450_000,
+                offset: Offset::Utc,
+                precision: TimePrecision::Second
             }
         })
     );
@@ -970,9 +977,9 @@ fn issue12_regression_1() {
             hour: 16,
             minute: 48,
             second: 1,
-            millisecond: 0,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
+            nanosecond: 0,
+            offset: Offset::Local,
+            precision: TimePrecision::Second
         }),
         time(input)
     );
@@ -983,16 +990,20 @@ fn issue12_regression_2() {
     let input = "04:05:06.1226001015632)*450";
 
     assert_eq!(
-        Ok(Time {
-            hour: 4,
-            minute: 5,
-            second: 6,
-            millisecond: 122,
-            tz_offset_hours: 0,
-            tz_offset_minutes: 0
-        }),
-        time(input)
+        Ok((
+            &b")*450"[..],
+            Time {
+                hour: 4,
+                minute: 5,
+                second: 6,
+                nanosecond: 122_600_101,
+                offset: Offset::Local,
+                precision: TimePrecision::Second
+            }
+        )),
+        parsers::parse_time(input.as_bytes())
     );
+    assert_eq!(ErrorKind::TrailingInput, time(input).unwrap_err().kind());
 }
 
 #[test]
@@ -1005,15 +1016,19 @@ fn test_duration_ymdhms() {
         Duration::YMDHMS {
             year: 1,
             month: 2,
+            week: 0,
             day: 3,
             hour: 4,
             minute: 5,
             second: 6,
-            millisecond: 0,
+            nanosecond: 0,
         },
         dur
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(36993906, 0));
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(36993906, 0)
+    );
 
     // full YMDHMS with milliseconds dot delimiter
     let dur = duration("P1Y2M3DT4H5M6.7S").unwrap();
@@ -1021,16 +1036,17 @@ fn test_duration_ymdhms() {
         Duration::YMDHMS {
             year: 1,
             month: 2,
+            week: 0,
             day: 3,
             hour: 4,
             minute: 5,
             second: 6,
-            millisecond: 700,
+            nanosecond: 700_000_000,
         },
         dur
     );
     assert_eq!(
-        StdDuration::from(dur),
+        StdDuration::try_from(dur).unwrap(),
         StdDuration::new(36993906, 700000000)
     );
 
@@ -1040,16 +1056,17 @@ fn test_duration_ymdhms() {
         Duration::YMDHMS {
             year: 1,
             month: 2,
+            week: 0,
             day: 3,
             hour: 4,
             minute: 5,
             second: 6,
-            millisecond: 700,
+            nanosecond: 700_000_000,
         },
         dur
     );
     assert_eq!(
-        StdDuration::from(dur),
+        StdDuration::try_from(dur).unwrap(),
         StdDuration::new(36993906, 700000000)
     );
 
@@ -1059,15 +1076,19 @@ fn test_duration_ymdhms() {
         Duration::YMDHMS {
             year: 1,
             month: 2,
+            week: 0,
             day: 0,
             hour: 4,
             minute: 5,
             second: 0,
-            millisecond: 0,
+            nanosecond: 0,
         },
         dur
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(36734700, 0));
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(36734700, 0)
+    );
 
     // subset Y-----
     let dur = duration("P1Y").unwrap();
@@ -1075,15 +1096,19 @@ fn test_duration_ymdhms() {
         Duration::YMDHMS {
             year: 1,
             month: 0,
+            week: 0,
             day: 0,
             hour: 0,
             minute: 0,
             second: 0,
-            millisecond: 0,
+            nanosecond: 0,
         },
         dur
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(31536000, 0));
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(31536000, 0)
+    );
 
     // subset ---H--
     let dur = duration("PT4H").unwrap();
@@ -1091,15 +1116,19 @@ fn test_duration_ymdhms() {
         Duration::YMDHMS {
             year: 0,
             month: 0,
+            week: 0,
             day: 0,
             hour: 4,
             minute: 0,
             second: 0,
-            millisecond: 0,
+            nanosecond: 0,
         },
         dur
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(14400, 0));
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(14400, 0)
+    );
 
     // subset -----S with milliseconds dot delimiter
     let dur = duration("PT6.7S").unwrap();
@@ -1107,15 +1136,19 @@ fn test_duration_ymdhms() {
         Duration::YMDHMS {
             year: 0,
             month: 0,
+            week: 0,
             day: 0,
             hour: 0,
             minute: 0,
             second: 6,
-            millisecond: 700,
+            nanosecond: 700_000_000,
         },
         dur
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(6, 700000000));
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(6, 700000000)
+    );
 
     // subset -----S with milliseconds comma delimiter
     let dur = duration("PT6,700S").unwrap();
@@ -1123,15 +1156,19 @@ fn test_duration_ymdhms() {
         Duration::YMDHMS {
             year: 0,
             month: 0,
+            week: 0,
             day: 0,
             hour: 0,
             minute: 0,
             second: 6,
-            millisecond: 700,
+            nanosecond: 700_000_000,
         },
         dur
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(6, 700000000));
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(6, 700000000)
+    );
 
     // empty duration, using Y
     let dur = duration("P0Y").unwrap();
@@ -1139,15 +1176,16 @@ fn test_duration_ymdhms() {
         Duration::YMDHMS {
             year: 0,
             month: 0,
+            week: 0,
             day: 0,
             hour: 0,
             minute: 0,
             second: 0,
-            millisecond: 0,
+            nanosecond: 0,
         },
         dur
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(0, 0));
+    assert_eq!(StdDuration::try_from(dur).unwrap(), StdDuration::new(0, 0));
 
     // empty duration, using S
     let dur = duration("PT0S").unwrap();
@@ -1155,60 +1193,73 @@ fn test_duration_ymdhms() {
         Duration::YMDHMS {
             year: 0,
             month: 0,
+            week: 0,
             day: 0,
             hour: 0,
             minute: 0,
             second: 0,
-            millisecond: 0,
+            nanosecond: 0,
         },
         dur
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(0, 0));
+    assert_eq!(StdDuration::try_from(dur).unwrap(), StdDuration::new(0, 0));
 
     let dur = duration("PT42M30S").unwrap();
     assert_eq!(
         Duration::YMDHMS {
             year: 0,
             month: 0,
+            week: 0,
             day: 0,
             hour: 0,
             minute: 42,
             second: 30,
-            millisecond: 0,
+            nanosecond: 0,
         },
         dur
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(2550, 0));
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(2550, 0)
+    );
 
     let dur = duration("P0001-02-03T04:05:06").unwrap();
     assert_eq!(
         Duration::YMDHMS {
             year: 1,
             month: 2,
+            week: 0,
             day: 3,
             hour: 4,
             minute: 5,
             second: 6,
-            millisecond: 0,
+            nanosecond: 0,
         },
         dur
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(36993906, 0));
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(36993906, 0)
+    );
 
     let dur = duration("P2018-04-27T00:00:00").unwrap();
     assert_eq!(
         Duration::YMDHMS {
             year: 2018,
             month: 4,
+            week: 0,
             day: 27,
             hour: 0,
             minute: 0,
             second: 0,
-            millisecond: 0,
+            nanosecond: 0,
         },
         dur
     );
-    assert_eq!(StdDuration::from(dur), StdDuration::new(63652348800, 0));
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(63652348800, 0)
+    );
 }
 
 #[test]
@@ -1217,13 +1268,19 @@ fn test_duration_weeks() {
 
     let dur = duration("P0W").unwrap();
     assert_eq!(Duration::Weeks(0), dur);
-    assert_eq!(StdDuration::from(dur), StdDuration::new(0, 0));
+    assert_eq!(StdDuration::try_from(dur).unwrap(), StdDuration::new(0, 0));
     let dur = duration("P26W").unwrap();
     assert_eq!(Duration::Weeks(26), dur);
-    assert_eq!(StdDuration::from(dur), StdDuration::new(15724800, 0));
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(15724800, 0)
+    );
     let dur = duration("P52W").unwrap();
     assert_eq!(Duration::Weeks(52), dur);
-    assert_eq!(StdDuration::from(dur), StdDuration::new(31449600, 0));
+    assert_eq!(
+        StdDuration::try_from(dur).unwrap(),
+        StdDuration::new(31449600, 0)
+    );
 }
 
 #[rustfmt::skip]
@@ -1259,7 +1316,7 @@ mod test_negative_and_expanded_years {
         assert_eq!(
             Ok(DateTime {
                 date: Date::YMD { year: -333, month: 7, day: 11 },
-                time: Time { hour: 10, minute: 0, second: 0, millisecond: 0, tz_offset_hours: 0, tz_offset_minutes: 0 },
+                time: Time { hour: 10, minute: 0, second: 0, nanosecond: 0, offset: Offset::Local, precision: TimePrecision::Second },
             }),
             datetime("-0333-07-11T10:00:00")
         );
@@ -1310,7 +1367,7 @@ mod test_leap_second {
     #[test]
     fn time_with_leap_second() {
         assert_eq!(
-            Ok(Time { hour: 23, minute: 59, second: 60, millisecond: 0, tz_offset_hours: 0, tz_offset_minutes: 0 }),
+            Ok(Time { hour: 23, minute: 59, second: 60, nanosecond: 0, offset: Offset::Local, precision: TimePrecision::Second }),
             time("23:59:60")
         );
     }
@@ -1320,11 +1377,38 @@ mod test_leap_second {
         assert_eq!(
             Ok(DateTime {
                 date: Date::YMD { year: 2016, month: 12, day: 31 },
-                time: Time { hour: 23, minute: 59, second: 60, millisecond: 0, tz_offset_hours: 0, tz_offset_minutes: 0 },
+                time: Time { hour: 23, minute: 59, second: 60, nanosecond: 0, offset: Offset::Utc, precision: TimePrecision::Second },
             }),
             datetime("2016-12-31T23:59:60Z")
         );
     }
+
+    #[test]
+    fn only_at_the_end_of_a_utc_day() {
+        for s in ["23:59:60Z", "23:59:60.5Z", "235960+0000", "00:59:60+01:00", "18:29:60-05:30", "23:59:60-00:00", "12:59:60", "23:59:60"] {
+            assert!(time(s).is_ok(), "{}", s);
+        }
+        for s in ["12:34:60+05:00", "23:59:60+01:00", "22:59:60Z", "23:58:60Z", "12:34:60", "23:59:60+00:00:30"] {
+            let err = time(s).unwrap_err();
+            assert_eq!((0, Some(Component::Second), ErrorKind::OutOfRange), (err.position(), err.component(), err.kind()), "{}", s);
+        }
+        assert_eq!(ErrorKind::OutOfRange, datetime("2016-12-31T12:34:60+05:00").unwrap_err().kind());
+    }
+
+    #[test]
+    fn known_leap_seconds() {
+        let options = ParseOptions::new().known_leap_seconds(true);
+        for s in ["1972-06-30T23:59:60Z", "1990-12-31T15:59:60-08:00", "2016-12-31T23:59:60.999Z", "2017-01-01T00:59:60+01:00", "2016-366T23:59:60Z", "2016-W52-6T23:59:60Z"] {
+            assert!(options.datetime(s).is_ok(), "{}", s);
+        }
+        for s in ["1971-12-31T23:59:60Z", "2017-12-31T23:59:60Z", "2016-12-30T23:59:60Z", "2016-06-30T22:59:60-01:00", "2016-02-31T23:59:60Z"] {
+            let err = options.datetime(s).unwrap_err();
+            assert_eq!((0, Some(Component::Second), ErrorKind::OutOfRange), (err.position(), err.component(), err.kind()), "{}", s);
+        }
+        // without a date there is nothing to look up
+        assert!(options.time("23:59:60Z").is_ok());
+        assert!(options.datetime("2017-12-31T23:59:59Z").is_ok());
+    }
 }
 
 #[rustfmt::skip]
@@ -1335,7 +1419,7 @@ mod test_end_of_day_midnight {
     #[test]
     fn time_24_00_00() {
         assert_eq!(
-            Ok(Time { hour: 24, minute: 0, second: 0, millisecond: 0, tz_offset_hours: 0, tz_offset_minutes: 0 }),
+            Ok(Time { hour: 24, minute: 0, second: 0, nanosecond: 0, offset: Offset::Local, precision: TimePrecision::Second }),
             time("24:00:00")
         );
     }
@@ -1345,11 +1429,38 @@ mod test_end_of_day_midnight {
         assert_eq!(
             Ok(DateTime {
                 date: Date::YMD { year: 2015, month: 6, day: 26 },
-                time: Time { hour: 24, minute: 0, second: 0, millisecond: 0, tz_offset_hours: 0, tz_offset_minutes: 0 },
+                time: Time { hour: 24, minute: 0, second: 0, nanosecond: 0, offset: Offset::Local, precision: TimePrecision::Second },
             }),
             datetime("2015-06-26T24:00:00")
         );
     }
+
+    #[test]
+    fn only_at_the_end_of_the_day() {
+        for s in ["24:00:01", "24:01", "24:59:59.999", "24:00:00.5", "24.5", "24:00.5", "240001Z"] {
+            let err = time(s).unwrap_err();
+            assert_eq!((0, Some(Component::Hour), ErrorKind::OutOfRange), (err.position(), err.component(), err.kind()), "{}", s);
+        }
+        assert!(time("24:00:00.000").is_ok());
+        assert!(time("24:00Z").is_ok());
+        assert_eq!(ErrorKind::OutOfRange, datetime("2015-06-26T24:30:00").unwrap_err().kind());
+    }
+
+    #[test]
+    fn normalize() {
+        let normalized = |s| datetime(s).unwrap().normalize().map(|dt| dt.to_string());
+        assert_eq!(Some("2015-06-27T00:00:00.000".into()), normalized("2015-06-26T24:00:00"));
+        assert_eq!(Some("2016-01-01T00:00Z".into()), normalized("2015-12-31T24:00Z"));
+        assert_eq!(Some("2016-02-29T00:00:00.000+01:00".into()), normalized("2016-02-28T24:00:00+01:00"));
+        assert_eq!(Some("2016-W01-1T00:00:00.000".into()), normalized("2015-W53-7T24:00:00"));
+        assert_eq!(Some("2016-001T00:00:00.000".into()), normalized("2015-365T24:00:00"));
+        assert_eq!(Some("2016-366T00:00:00.000".into()), normalized("2016-365T24:00:00"));
+        // any other time stays as it is
+        assert_eq!(Some("2015-06-26T23:59:59.000".into()), normalized("2015-06-26T23:59:59"));
+        assert_eq!(Some("2015-02-29T10:00:00.000".into()), normalized("2015-02-29T10:00:00"));
+        // the next day has to be known
+        assert_eq!(None, normalized("2015-02-29T24:00:00"));
+    }
 }
 
 #[rustfmt::skip]
@@ -1359,7 +1470,7 @@ mod test_timezone_offset_variants {
     #[test]
     fn hour_only_positive_offset() {
         assert_eq!(
-            Ok(Time { hour: 16, minute: 43, second: 16, millisecond: 0, tz_offset_hours: 5, tz_offset_minutes: 0 }),
+            Ok(Time { hour: 16, minute: 43, second: 16, nanosecond: 0, offset: Offset::Fixed(UtcOffset::from_hms(5, 0, 0).unwrap()), precision: TimePrecision::Second }),
             time("16:43:16+05")
         );
     }
@@ -1367,7 +1478,7 @@ mod test_timezone_offset_variants {
     #[test]
     fn hour_only_negative_offset() {
         assert_eq!(
-            Ok(Time { hour: 16, minute: 43, second: 16, millisecond: 0, tz_offset_hours: -5, tz_offset_minutes: 0 }),
+            Ok(Time { hour: 16, minute: 43, second: 16, nanosecond: 0, offset: Offset::Fixed(UtcOffset::from_hms(-5, 0, 0).unwrap()), precision: TimePrecision::Second }),
             time("16:43:16-05")
         );
     }
@@ -1375,7 +1486,7 @@ mod test_timezone_offset_variants {
     #[test]
     fn basic_format_with_hour_only_offset() {
         assert_eq!(
-            Ok(Time { hour: 16, minute: 43, second: 16, millisecond: 0, tz_offset_hours: 5, tz_offset_minutes: 0 }),
+            Ok(Time { hour: 16, minute: 43, second: 16, nanosecond: 0, offset: Offset::Fixed(UtcOffset::from_hms(5, 0, 0).unwrap()), precision: TimePrecision::Second }),
             time("164316+05")
         );
     }
@@ -1384,7 +1495,7 @@ mod test_timezone_offset_variants {
     #[test]
     fn quarter_hour_offset() {
         assert_eq!(
-            Ok(Time { hour: 16, minute: 43, second: 16, millisecond: 0, tz_offset_hours: 13, tz_offset_minutes: 45 }),
+            Ok(Time { hour: 16, minute: 43, second: 16, nanosecond: 0, offset: Offset::Fixed(UtcOffset::from_hms(13, 45, 0).unwrap()), precision: TimePrecision::Second }),
             time("16:43:16+13:45")
         );
     }
@@ -1430,14 +1541,1793 @@ mod test_real_calendar_leap_day {
 mod test_duration_datetime_format_with_timezone {
     use super::*;
 
-    // The <duration> = "P" <datetime> form embeds a full time, which may carry
-    // a timezone offset; the offset itself has no representation in `Duration`
-    // and is simply dropped.
+    // The <duration> = "P" <datetime> form looks like a datetime,
+    // but a timezone offset has no meaning in a duration.
+    #[test]
+    fn offset_is_rejected() {
+        assert_eq!(ErrorKind::TrailingInput, duration("P2015-11-03T21:56:00+02:00").unwrap_err().kind());
+        assert_eq!(ErrorKind::TrailingInput, duration("P2015-11-03T21:56:00Z").unwrap_err().kind());
+    }
+}
+
+#[rustfmt::skip]
+mod test_parse_error {
+    use super::*;
+
+    #[test]
+    fn month_out_of_range() {
+        let err = date("2015-13-01").unwrap_err();
+        assert_eq!(err.position(), 5);
+        assert_eq!(err.component(), Some(Component::Month));
+        assert_eq!(err.kind(), ErrorKind::OutOfRange);
+    }
+
+    #[test]
+    fn unexpected_character_in_year() {
+        let err = date("2a15-11-02").unwrap_err();
+        assert_eq!(err.position(), 1);
+        assert_eq!(err.component(), Some(Component::Year));
+        assert_eq!(err.kind(), ErrorKind::UnexpectedCharacter);
+    }
+
+    #[test]
+    fn missing_datetime_separator() {
+        let err = datetime("2015-11-02X10:00").unwrap_err();
+        assert_eq!(err.position(), 10);
+        assert_eq!(err.component(), Some(Component::Separator));
+        assert_eq!(err.kind(), ErrorKind::UnexpectedCharacter);
+    }
+
+    #[test]
+    fn truncated_time() {
+        let err = time("10:3").unwrap_err();
+        assert_eq!(err.position(), 4);
+        assert_eq!(err.component(), Some(Component::Minute));
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEnd);
+    }
+
+    #[test]
+    fn unknown_duration_unit() {
+        let err = duration("P1X").unwrap_err();
+        assert_eq!(err.position(), 2);
+        assert_eq!(err.component(), Some(Component::Designator));
+        assert_eq!(err.kind(), ErrorKind::UnexpectedCharacter);
+    }
+
+    #[test]
+    fn duration_number_too_large() {
+        let err = duration("P99999999999D").unwrap_err();
+        assert_eq!(err.position(), 1);
+        assert_eq!(err.component(), Some(Component::Day));
+        assert_eq!(err.kind(), ErrorKind::OutOfRange);
+    }
+
+    #[test]
+    fn from_str_error() {
+        let err = "2015-11-02T25:00".parse::<DateTime>().unwrap_err();
+        assert_eq!(err.to_string(), "value out of range in hour at position 11");
+    }
+}
+
+#[rustfmt::skip]
+mod test_trailing_input {
+    use super::*;
+
+    #[test]
+    fn datetime_with_trailing_garbage() {
+        let err = datetime("2015-11-02T10:00Zjunk").unwrap_err();
+        assert_eq!(err.position(), 17);
+        assert_eq!(err.kind(), ErrorKind::TrailingInput);
+    }
+
+    #[test]
+    fn date_followed_by_time() {
+        let err = date("2015-11-02T10:00").unwrap_err();
+        assert_eq!(err.position(), 10);
+        assert_eq!(err.kind(), ErrorKind::TrailingInput);
+    }
+
+    #[test]
+    fn time_with_invalid_offset() {
+        let err = time("10:00+25:00").unwrap_err();
+        assert_eq!(err.position(), 5);
+        assert_eq!(err.kind(), ErrorKind::TrailingInput);
+    }
+
+    #[test]
+    fn duration_with_trailing_garbage() {
+        let err = duration("P1DT2Hjunk").unwrap_err();
+        assert_eq!(err.position(), 6);
+        assert_eq!(err.kind(), ErrorKind::TrailingInput);
+    }
+
+    #[test]
+    fn low_level_parsers_keep_leftovers() {
+        let (rest, _) = parsers::parse_datetime(b"2015-11-02T10:00Zjunk").unwrap();
+        assert_eq!(rest, b"junk");
+    }
+}
+
+#[rustfmt::skip]
+mod test_calendar_validity {
+    use super::*;
+
+    #[test]
+    fn month_lengths() {
+        assert!(date("2015-01-31").unwrap().is_valid());
+        assert_eq!(date("2015-04-31").unwrap().validate(), Err(InvalidDate::DayOfMonth));
+        assert_eq!(date("2015-06-31").unwrap().validate(), Err(InvalidDate::DayOfMonth));
+        assert_eq!(date("2016-02-30").unwrap().validate(), Err(InvalidDate::DayOfMonth));
+    }
+
+    #[test]
+    fn leap_years() {
+        assert!(date("2016-02-29").unwrap().is_valid());
+        assert!(date("2000-02-29").unwrap().is_valid());
+        assert_eq!(date("1900-02-29").unwrap().validate(), Err(InvalidDate::LeapDay));
+        assert_eq!(date("2015-02-29").unwrap().validate(), Err(InvalidDate::LeapDay));
+    }
+
+    #[test]
+    fn ordinal_days() {
+        assert!(date("2016-366").unwrap().is_valid());
+        assert_eq!(date("2015-366").unwrap().validate(), Err(InvalidDate::DayOfYear));
+    }
+
+    #[test]
+    fn long_iso_years() {
+        assert!(date("2015-W53-7").unwrap().is_valid());
+        assert!(date("2020-W53-1").unwrap().is_valid());
+        assert!(date("2004-W53-1").unwrap().is_valid());
+        assert_eq!(date("2014-W53-1").unwrap().validate(), Err(InvalidDate::Week));
+        assert_eq!(date("2019-W53-1").unwrap().validate(), Err(InvalidDate::Week));
+    }
+
+    #[test]
+    fn weeks_at_range_edges() {
+        for year in [i32::MIN, i32::MIN + 1, 2_000_000_000, i32::MAX - 1, i32::MAX] {
+            assert!(Date::Week { year, ww: 1, d: 1 }.is_valid(), "{}", year);
+            assert!(Date::YearWeek { year, ww: 52 }.is_valid(), "{}", year);
+        }
+        // neither the first nor the last year of the range has 53 weeks
+        assert_eq!(Date::Week { year: i32::MIN, ww: 53, d: 1 }.validate(), Err(InvalidDate::Week));
+        assert_eq!(Date::Week { year: i32::MAX, ww: 53, d: 7 }.validate(), Err(InvalidDate::Week));
+        assert_eq!(Date::YearWeek { year: i32::MAX, ww: 53 }.validate(), Err(InvalidDate::Week));
+        assert_eq!(Date::Week { year: i32::MAX, ww: 1, d: 8 }.validate(), Err(InvalidDate::WeekDay));
+    }
+
+    #[test]
+    fn default_date_is_invalid() {
+        assert_eq!(Date::default().validate(), Err(InvalidDate::Month));
+    }
+
+    #[test]
+    fn validating_parse() {
+        let options = ParseOptions::new().validate(true);
+
+        assert_eq!(
+            Ok(Date::YMD { year: 2016, month: 2, day: 29 }),
+            options.date("2016-02-29")
+        );
+
+        let err = options.date("2015-02-29").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidDate(InvalidDate::LeapDay));
+        assert_eq!(err.component(), Some(Component::Day));
+
+        let err = options.datetime("2015-W53-8T10:00:00").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::OutOfRange);
+
+        let err = options.datetime("2019-W53-1T10:00:00").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidDate(InvalidDate::Week));
+        assert_eq!(err.to_string(), "invalid date (week does not exist in year) in week at position 0");
+    }
+
+    #[test]
+    fn parsing_does_not_validate_by_default() {
+        assert!(date("2015-02-29").is_ok());
+        assert!(ParseOptions::new().date("2015-02-29").is_ok());
+    }
+}
+
+#[rustfmt::skip]
+mod test_date_conversions {
+    use super::*;
+
+    #[test]
+    fn ymd_to_week_and_ordinal() {
+        let d = date("2015-11-02").unwrap();
+        assert_eq!(d.to_week(), Some(Date::Week { year: 2015, ww: 45, d: 1 }));
+        assert_eq!(d.to_ordinal(), Some(Date::Ordinal { year: 2015, ddd: 306 }));
+        assert_eq!(d.to_ymd(), Some(d));
+    }
+
+    #[test]
+    fn week_year_rollover() {
+        // 2008-12-29 is the Monday of week 1 of 2009
+        assert_eq!(
+            date("2008-12-29").unwrap().to_week(),
+            Some(Date::Week { year: 2009, ww: 1, d: 1 })
+        );
+        assert_eq!(
+            date("2009-W01-1").unwrap().to_ymd(),
+            Some(Date::YMD { year: 2008, month: 12, day: 29 })
+        );
+        // 2010-01-03 is the Sunday of week 53 of 2009
+        assert_eq!(
+            date("2010-01-03").unwrap().to_week(),
+            Some(Date::Week { year: 2009, ww: 53, d: 7 })
+        );
+        assert_eq!(
+            date("2009-W53-7").unwrap().to_ordinal(),
+            Some(Date::Ordinal { year: 2010, ddd: 3 })
+        );
+        // 2006-01-01 is the Sunday of week 52 of 2005
+        assert_eq!(
+            date("2006-001").unwrap().to_week(),
+            Some(Date::Week { year: 2005, ww: 52, d: 7 })
+        );
+    }
+
+    #[test]
+    fn leap_days() {
+        assert_eq!(
+            date("2016-366").unwrap().to_ymd(),
+            Some(Date::YMD { year: 2016, month: 12, day: 31 })
+        );
+        assert_eq!(
+            date("2016-02-29").unwrap().to_ordinal(),
+            Some(Date::Ordinal { year: 2016, ddd: 60 })
+        );
+        assert_eq!(
+            date("-0004-02-29").unwrap().to_ordinal(),
+            Some(Date::Ordinal { year: -4, ddd: 60 })
+        );
+    }
+
+    #[test]
+    fn invalid_dates_do_not_convert() {
+        assert_eq!(date("2015-02-29").unwrap().to_week(), None);
+        assert_eq!(date("2015-366").unwrap().to_ymd(), None);
+        assert_eq!(date("2014-W53-1").unwrap().to_ordinal(), None);
+    }
+
+    #[test]
+    fn roundtrip_every_day() {
+        for year in [-401, -1, 0, 1, 1899, 1900, 1970, 2000, 2004, 2015, 2020, 2100] {
+            for ddd in 1..=if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) { 366 } else { 365 } {
+                let ordinal = Date::Ordinal { year, ddd };
+                let week = ordinal.to_week().unwrap();
+                let ymd = week.to_ymd().unwrap();
+                assert!(week.is_valid() && ymd.is_valid(), "{:?}", ordinal);
+                assert_eq!(ymd.to_ordinal(), Some(ordinal));
+                assert_eq!(ymd.to_week(), Some(week));
+            }
+        }
+    }
+
+    #[test]
+    fn range_edges() {
+        // i32::MIN-01-01 is a Tuesday, its ISO year starts the day before
+        assert_eq!(Date::Week { year: i32::MIN, ww: 1, d: 1 }.to_ymd(), None);
+        assert_eq!(Date::Week { year: i32::MIN, ww: 1, d: 2 }.to_ymd(), Some(Date::YMD { year: i32::MIN, month: 1, day: 1 }));
+        assert_eq!(Date::YMD { year: i32::MIN, month: 1, day: 1 }.to_week(), Some(Date::Week { year: i32::MIN, ww: 1, d: 2 }));
+        assert_eq!(Date::Ordinal { year: i32::MIN, ddd: 1 }.to_ymd(), Some(Date::YMD { year: i32::MIN, month: 1, day: 1 }));
+
+        // i32::MAX-12-31 is a Tuesday, in week 1 of the next ISO year
+        assert_eq!(Date::YMD { year: i32::MAX, month: 12, day: 31 }.to_week(), None);
+        assert_eq!(Date::YMD { year: i32::MAX, month: 12, day: 31 }.to_ordinal(), Some(Date::Ordinal { year: i32::MAX, ddd: 365 }));
+        assert_eq!(Date::YMD { year: i32::MAX, month: 12, day: 29 }.to_week(), Some(Date::Week { year: i32::MAX, ww: 52, d: 7 }));
+        assert_eq!(Date::Week { year: i32::MAX, ww: 52, d: 7 }.to_ymd(), Some(Date::YMD { year: i32::MAX, month: 12, day: 29 }));
+
+        let week = Date::Week { year: 2_000_000_000, ww: 1, d: 1 };
+        assert_eq!(week.to_ymd().and_then(|ymd| ymd.to_week()), Some(week));
+    }
+}
+
+#[rustfmt::skip]
+mod test_reduced_precision_dates {
+    use super::*;
+
+    #[test]
+    fn year_month() {
+        assert_eq!(Ok(Date::YearMonth { year: 2015, month: 11 }), date("2015-11"));
+    }
+
+    #[test]
+    fn year_week() {
+        assert_eq!(Ok(Date::YearWeek { year: 2015, ww: 45 }), date("2015-W45"));
+        assert_eq!(Ok(Date::YearWeek { year: 2015, ww: 45 }), date("2015W45"));
+    }
+
+    #[test]
+    fn year() {
+        assert_eq!(Ok(Date::Year { year: 2015 }), date("2015"));
+    }
+
+    #[test]
+    fn century() {
+        assert_eq!(Ok(Date::Century { century: 20 }), date("20"));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(date("2015-11").unwrap().to_string(), "2015-11");
+        assert_eq!(date("2015W45").unwrap().to_string(), "2015-W45");
+        assert_eq!(date("2015").unwrap().to_string(), "2015");
+        assert_eq!(date("20").unwrap().to_string(), "20");
+    }
+
+    #[test]
+    fn validity() {
+        assert!(date("2015-W53").unwrap().is_valid());
+        assert_eq!(date("2014-W53").unwrap().validate(), Err(InvalidDate::Week));
+        assert_eq!(date("2015-11").unwrap().to_ymd(), None);
+    }
+
+    #[test]
+    fn not_allowed_in_datetime() {
+        assert!(datetime("2015-11T10:00:00").is_err());
+    }
+}
+
+#[rustfmt::skip]
+mod test_expanded_years {
+    use super::*;
+
+    #[test]
+    fn extended_format_by_default() {
+        assert_eq!(Ok(Date::YMD { year: 12345, month: 1, day: 1 }), date("+12345-01-01"));
+        assert_eq!(Ok(Date::Ordinal { year: -12345, ddd: 1 }), date("-12345-001"));
+        assert_eq!(Ok(Date::Year { year: 275760 }), date("+275760"));
+        assert!(datetime("+12345-01-01T10:00:00Z").is_ok());
+    }
+
+    #[test]
+    fn agreed_number_of_digits() {
+        let options = ParseOptions::new().expanded_year(2);
+
+        assert_eq!(Ok(Date::YMD { year: 12345, month: 1, day: 1 }), options.date("+012345-01-01"));
+        assert_eq!(Ok(Date::YMD { year: 2015, month: 11, day: 2 }), options.date("+0020151102"));
+        assert_eq!(Ok(Date::Week { year: -2015, ww: 45, d: 1 }), options.date("-002015-W45-1"));
+        assert_eq!(Ok(Date::Year { year: 2015 }), options.date("+002015"));
+        assert_eq!(Ok(Date::Century { century: 20 }), options.date("+0020"));
+        assert!(options.datetime("+002015-11-02T10:00:00Z").is_ok());
+
+        let err = options.date("2015-11-02").unwrap_err();
+        assert_eq!(err.component(), Some(Component::Year));
+        assert!(options.date("+12345-01-01").is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(date("+12345-01-01").unwrap().to_string(), "+12345-01-01");
+        assert_eq!(date("-12345-01-01").unwrap().to_string(), "-12345-01-01");
+        assert_eq!(date("2015-01-01").unwrap().display_expanded(2).to_string(), "+002015-01-01");
+        assert_eq!(date("-0333-W07-2").unwrap().display_expanded(1).to_string(), "-00333-W07-2");
+        assert_eq!(date("20").unwrap().display_expanded(2).to_string(), "+0020");
+    }
+
+    #[test]
+    fn centuries() {
+        assert_eq!(Ok(Date::Century { century: 123 }), date("+123"));
+        assert_eq!(Ok(Date::Century { century: -100 }), date("-100"));
+        assert_eq!(Ok(Date::Year { year: 1234 }), date("+1234"));
+        assert!(date("123").is_err());
+        for century in [123, -999, 100] {
+            let date = Date::Century { century };
+            assert_eq!(iso8601::date(&date.to_string()), Ok(date));
+        }
+        // beyond three digits only with the agreed number
+        let date = Date::Century { century: 12345 };
+        assert_eq!(ParseOptions::new().expanded_year(3).date(&date.display_expanded(3).to_string()), Ok(date));
+    }
+
+    #[test]
+    fn display_roundtrip() {
+        let options = ParseOptions::new().expanded_year(3);
+        for s in ["+1234567-12-31", "-0000001-001", "+0002015-W53"] {
+            let date = options.date(s).unwrap();
+            assert_eq!(date.display_expanded(3).to_string(), s);
+            assert_eq!(options.date(&date.display_expanded(3).to_string()), Ok(date));
+        }
+    }
+}
+
+#[rustfmt::skip]
+mod test_nanosecond_precision {
+    use super::*;
+
+    #[test]
+    fn time_fraction() {
+        assert_eq!(123_456_789, time("12:00:00.123456789").unwrap().nanosecond);
+        assert_eq!(123_456_000, time("12:00:00,123456").unwrap().nanosecond);
+        assert_eq!(1, time("12:00:00.000000001Z").unwrap().nanosecond);
+        // digits beyond nanoseconds are truncated
+        assert_eq!(123_456_789, time("12:00:00.1234567891").unwrap().nanosecond);
+    }
+
+    #[test]
+    fn duration_fraction() {
+        assert_eq!(
+            Ok(Duration::YMDHMS { year: 0, month: 0, week: 0, day: 0, hour: 0, minute: 0, second: 1, nanosecond: 1 }),
+            duration("PT1.000000001S")
+        );
+    }
+
+    #[test]
+    fn display_stored_precision() {
+        assert_eq!("12:00:00.123", time("12:00:00.123").unwrap().to_string());
+        assert_eq!("12:00:00.123450", time("12:00:00.12345").unwrap().to_string());
+        assert_eq!("12:00:00.123456789", time("12:00:00.123456789").unwrap().to_string());
+        assert_eq!("PT1.000001S", duration("PT1.000001S").unwrap().to_string());
+    }
+}
+
+#[rustfmt::skip]
+mod test_fractional_hours_and_minutes {
+    use super::*;
+
+    fn hms(time: Time) -> (u32, u32, u32, u32) {
+        (time.hour, time.minute, time.second, time.nanosecond)
+    }
+
+    fn dhms(duration: Duration) -> (i32, i32, i32, i32, i32) {
+        match duration {
+            Duration::YMDHMS { day, hour, minute, second, nanosecond, .. } => (day, hour, minute, second, nanosecond),
+            Duration::Weeks(_) => panic!("expected YMDHMS"),
+        }
+    }
+
+    #[test]
+    fn time() {
+        assert_eq!((10, 15, 0, 0), hms(super::time("10.25").unwrap()));
+        assert_eq!((10, 30, 30, 0), hms(super::time("10:30.5").unwrap()));
+        assert_eq!((10, 30, 30, 0), hms(super::time("1030,5").unwrap()));
+        assert_eq!((10, 0, 0, 3_600_000), hms(super::time("10.000001").unwrap()));
+        assert_eq!((10, 15, 0, 0), hms(datetime("2015-06-26T10.25Z").unwrap().time));
+        assert_eq!(Offset::Fixed(UtcOffset::from_hms(-5, 0, 0).unwrap()), super::time("10.25-05").unwrap().offset);
+    }
+
     #[test]
-    fn offset_is_accepted_and_ignored() {
+    fn time_fraction_on_lowest_order_only() {
+        assert_eq!(ErrorKind::TrailingInput, super::time("10.5:30").unwrap_err().kind());
+        assert_eq!(ErrorKind::TrailingInput, super::time("10:30.5:00").unwrap_err().kind());
+    }
+
+    #[test]
+    fn duration() {
+        assert_eq!((0, 2, 30, 0, 0), dhms(super::duration("PT2.5H").unwrap()));
+        assert_eq!((0, 12, 0, 0, 0), dhms(super::duration("P0.5D").unwrap()));
+        assert_eq!((1, 6, 0, 0, 0), dhms(super::duration("P1,25D").unwrap()));
+        assert_eq!((0, 1, 1, 30, 0), dhms(super::duration("PT1H1.5M").unwrap()));
         assert_eq!(
-            Ok(Duration::YMDHMS { year: 2015, month: 11, day: 3, hour: 21, minute: 56, second: 0, millisecond: 0 }),
-            duration("P2015-11-03T21:56:00+02:00")
+            core::time::Duration::from_secs(9000),
+            super::duration("PT2.5H").unwrap().try_into().unwrap()
         );
     }
+
+    #[test]
+    fn duration_fraction_on_lowest_order_only() {
+        assert_eq!(ErrorKind::TrailingInput, super::duration("PT1.5H30M").unwrap_err().kind());
+        assert_eq!(ErrorKind::TrailingInput, super::duration("P0.5DT1H").unwrap_err().kind());
+        assert!(super::duration("P1.5Y").is_err());
+        assert!(super::duration("P1.5M").is_err());
+        assert!(super::duration("P1.5W").is_err());
+    }
+}
+
+#[rustfmt::skip]
+mod test_interval {
+    use super::*;
+
+    fn ymd(year: i32, month: u32, day: u32) -> Date {
+        Date::YMD { year, month, day }
+    }
+
+    fn at(date: Date, hour: u32, minute: u32) -> IntervalEndpoint {
+        IntervalEndpoint::DateTime(DateTime { date, time: Time { hour, minute, precision: TimePrecision::Minute, ..Time::default() } })
+    }
+
+    fn at_utc(date: Date, hour: u32, minute: u32) -> IntervalEndpoint {
+        IntervalEndpoint::DateTime(DateTime { date, time: Time { hour, minute, offset: Offset::Utc, precision: TimePrecision::Minute, ..Time::default() } })
+    }
+
+    fn at_utc_seconds(date: Date, hour: u32, minute: u32) -> IntervalEndpoint {
+        IntervalEndpoint::DateTime(DateTime { date, time: Time { hour, minute, offset: Offset::Utc, ..Time::default() } })
+    }
+
+    #[test]
+    fn start_end() {
+        assert_eq!(
+            Ok(Interval::StartEnd { start: at_utc_seconds(ymd(2007, 3, 1), 13, 0), end: at_utc_seconds(ymd(2008, 5, 11), 15, 30) }),
+            interval("2007-03-01T13:00:00Z/2008-05-11T15:30:00Z")
+        );
+        assert_eq!(
+            Ok(Interval::StartEnd {
+                start: IntervalEndpoint::Date(Date::YearMonth { year: 2004, month: 6 }),
+                end: IntervalEndpoint::Date(Date::YearMonth { year: 2006, month: 8 }),
+            }),
+            interval("2004-06/2006-08")
+        );
+    }
+
+    #[test]
+    fn start_duration() {
+        assert_eq!(
+            Ok(Interval::StartDuration {
+                start: at_utc_seconds(ymd(2007, 3, 1), 13, 0),
+                duration: Duration::YMDHMS { year: 1, month: 2, week: 0, day: 10, hour: 2, minute: 30, second: 0, nanosecond: 0 },
+            }),
+            interval("2007-03-01T13:00:00Z/P1Y2M10DT2H30M")
+        );
+        assert_eq!(
+            Ok(Interval::StartDuration { start: IntervalEndpoint::Date(ymd(2015, 6, 26)), duration: Duration::Weeks(2) }),
+            interval("2015-06-26/P2W")
+        );
+    }
+
+    #[test]
+    fn duration_end() {
+        assert_eq!(
+            Ok(Interval::DurationEnd {
+                duration: Duration::YMDHMS { year: 1, month: 2, week: 0, day: 10, hour: 2, minute: 30, second: 0, nanosecond: 0 },
+                end: at_utc_seconds(ymd(2008, 5, 11), 15, 30),
+            }),
+            interval("P1Y2M10DT2H30M/2008-05-11T15:30:00Z")
+        );
+    }
+
+    #[test]
+    fn abbreviated_end() {
+        let start_end = |start, end| Ok(Interval::StartEnd { start, end });
+
+        assert_eq!(start_end(IntervalEndpoint::Date(ymd(2008, 2, 15)), IntervalEndpoint::Date(ymd(2008, 3, 14))), interval("2008-02-15/03-14"));
+        assert_eq!(start_end(IntervalEndpoint::Date(ymd(2008, 2, 15)), IntervalEndpoint::Date(ymd(2008, 2, 20))), interval("20080215/20"));
+        assert_eq!(start_end(at(ymd(2007, 12, 14), 13, 30), at(ymd(2007, 12, 14), 15, 30)), interval("2007-12-14T13:30/15:30"));
+        assert_eq!(start_end(at(ymd(2007, 11, 13), 9, 0), at(ymd(2007, 11, 15), 17, 0)), interval("2007-11-13T09:00/15T17:00"));
+        assert_eq!(start_end(at(ymd(2007, 11, 13), 9, 0), at(ymd(2008, 1, 2), 17, 0)), interval("2007-11-13T09:00/2008-01-02T17:00"));
+        // the end has the start's offset unless it gives its own
+        assert_eq!(start_end(at_utc(ymd(2007, 12, 14), 13, 30), at_utc(ymd(2007, 12, 14), 15, 30)), interval("2007-12-14T13:30Z/15:30"));
+        assert_eq!(start_end(at_utc(ymd(2007, 11, 13), 9, 0), at_utc(ymd(2007, 11, 15), 17, 0)), interval("2007-11-13T09:00Z/15T17:00"));
+        assert_eq!(start_end(at(ymd(2007, 12, 14), 13, 30), at_utc(ymd(2007, 12, 14), 15, 30)), interval("2007-12-14T13:30/15:30Z"));
+    }
+
+    #[test]
+    fn abbreviated_end_after_time() {
+        let start_end = |start, end| Ok(Interval::StartEnd { start, end });
+
+        // a time after a start with a time, not a month and day
+        assert_eq!(start_end(at(ymd(2015, 11, 2), 10, 30), at(ymd(2015, 11, 2), 11, 30)), interval("2015-11-02T10:30/1130"));
+        assert_eq!(start_end(at(ymd(2015, 11, 2), 10, 30), at(ymd(2015, 11, 30), 12, 0)), interval("2015-11-02T10:30/1130T12:00"));
+        assert_eq!(start_end(IntervalEndpoint::Date(ymd(2015, 11, 2)), IntervalEndpoint::Date(ymd(2015, 11, 30))), interval("2015-11-02/1130"));
+
+        // an end before the start, neither the 11th nor the century
+        for s in ["2008-02-15T10:00/11", "2008-02-15T10:00/0930", "2008-02-15/11", "2008-02-15/01-20", "2004-06/05", "2004-W06/W05", "2004-060/059"] {
+            let err = interval(s).unwrap_err();
+            assert_eq!((s.find('/').unwrap() + 1, ErrorKind::OutOfRange), (err.position(), err.kind()), "{}", s);
+        }
+        assert_eq!(start_end(at(ymd(2008, 2, 15), 10, 0), IntervalEndpoint::Date(ymd(2008, 2, 15))), interval("2008-02-15T10:00/15"));
+        assert_eq!(start_end(at(ymd(2008, 2, 15), 10, 0), at(ymd(2008, 2, 15), 10, 0)), interval("2008-02-15T10:00/10:00"));
+    }
+
+    #[test]
+    fn abbreviated_end_of_reduced_start() {
+        let start_end = |start, end| Ok(Interval::StartEnd { start: IntervalEndpoint::Date(start), end: IntervalEndpoint::Date(end) });
+
+        assert_eq!(start_end(Date::YearMonth { year: 2004, month: 6 }, Date::YearMonth { year: 2004, month: 8 }), interval("2004-06/08"));
+        assert_eq!(start_end(Date::YearWeek { year: 2004, ww: 6 }, Date::YearWeek { year: 2004, ww: 8 }), interval("2004-W06/W08"));
+        assert_eq!(start_end(Date::Ordinal { year: 2004, ddd: 60 }, Date::Ordinal { year: 2004, ddd: 75 }), interval("2004-060/075"));
+        assert_eq!(start_end(Date::Week { year: 2004, ww: 6, d: 1 }, Date::Week { year: 2004, ww: 8, d: 5 }), interval("2004-W06-1/W08-5"));
+        assert_eq!(
+            Ok(Interval::StartEnd { start: at(Date::Ordinal { year: 2004, ddd: 60 }, 9, 0), end: at(Date::Ordinal { year: 2004, ddd: 61 }, 17, 0) }),
+            interval("2004-060T09:00/061T17:00")
+        );
+        // a month has no time
+        assert!(interval("2004-06/08T10:00").is_err());
+    }
+
+    #[test]
+    fn validation() {
+        let options = ParseOptions::new().validate(true);
+
+        assert!(interval("2015-02-15/02-30").is_ok());
+        let err = options.interval("2015-02-15/02-30").unwrap_err();
+        assert_eq!(ErrorKind::InvalidDate(InvalidDate::DayOfMonth), err.kind());
+        assert_eq!(11, err.position());
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(ErrorKind::TrailingInput, interval("2015-06-26/P1D/P1D").unwrap_err().kind());
+        assert_eq!(Some(Component::Separator), interval("2015-06-26 2015-06-27").unwrap_err().component());
+        assert!(interval("P1D/P1D").is_err());
+        assert!(interval("2015-W45-1/2-3").is_err());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            "2007-12-14T13:30/2007-12-14T15:30",
+            interval("2007-12-14T13:30/15:30").unwrap().to_string()
+        );
+        assert_eq!("P1W/2015-06-26", interval("P1W/2015-06-26").unwrap().to_string());
+    }
+}
+
+#[rustfmt::skip]
+mod test_repeating_interval {
+    use super::*;
+
+    fn occurrences(s: &str) -> Vec<String> {
+        repeating_interval(s).unwrap().occurrences().unwrap().map(|dt| dt.to_string()).collect()
+    }
+
+    #[test]
+    fn parse() {
+        let repeating = repeating_interval("R5/2008-03-01T13:00:00Z/P1Y2M10DT2H30M").unwrap();
+        assert_eq!(Some(5), repeating.repetitions);
+        assert_eq!(interval("2008-03-01T13:00:00Z/P1Y2M10DT2H30M"), Ok(repeating.interval));
+
+        let unbounded = repeating_interval("R/P1D/2008-03-01").unwrap();
+        assert_eq!(None, unbounded.repetitions);
+
+        let err = repeating_interval("5/2008-03-01/P1D").unwrap_err();
+        assert_eq!(Some(Component::Designator), err.component());
+        assert_eq!(0, err.position());
+    }
+
+    #[test]
+    fn occurrences_of_duration() {
+        assert_eq!(
+            vec![
+                "2008-03-01T13:00:00.000Z",
+                "2009-05-11T15:30:00.000Z",
+                "2010-07-21T18:00:00.000Z",
+            ],
+            occurrences("R3/2008-03-01T13:00:00Z/P1Y2M10DT2H30M")
+        );
+        assert_eq!(
+            vec!["2015-12-28T23:00:00.000-05:00", "2016-01-04T23:00:00.000-05:00"],
+            occurrences("R2/2015-12-28T23:00:00-05:00/P1W")
+        );
+        assert!(occurrences("R0/2015-12-28T23:00:00Z/P1W").is_empty());
+    }
+
+    #[test]
+    fn occurrences_clamp_to_end_of_month() {
+        assert_eq!(
+            vec!["2016-01-31", "2016-02-29", "2016-03-31", "2016-04-30"],
+            repeating_interval("R4/2016-01-31T10:00Z/P1M").unwrap().occurrences().unwrap().map(|dt| dt.date.to_string()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn occurrences_carry_over_days() {
+        assert_eq!(
+            vec!["2015-12-31T22:00Z", "2016-01-01T08:30Z", "2016-01-01T19:00Z"],
+            occurrences("R3/2015-12-31T22:00Z/PT10.5H")
+        );
+    }
+
+    #[test]
+    fn occurrences_keep_representation() {
+        assert_eq!(
+            vec!["2015-W53-7T12:00Z", "2016-W01-1T12:00Z"],
+            occurrences("R2/2015-W53-7T12:00Z/P1D")
+        );
+    }
+
+    #[test]
+    fn occurrences_of_start_and_end() {
+        assert_eq!(
+            vec!["2008-03-01T13:00:00.000Z", "2008-03-01T15:30:00.000Z", "2008-03-01T18:00:00.000Z"],
+            occurrences("R3/2008-03-01T13:00:00Z/15:30:00Z")
+        );
+        // the end's offset is taken into account, the occurrences keep the start's
+        assert_eq!(
+            vec!["2008-03-01T13:00:00.000Z", "2008-03-01T14:00:00.000Z"],
+            occurrences("R2/2008-03-01T13:00:00Z/2008-03-01T15:00:00+01:00")
+        );
+    }
+
+    #[test]
+    fn unbounded() {
+        let repeating = repeating_interval("R/2008-03-01T13:00:00Z/P1D").unwrap();
+        assert_eq!(
+            Some("2010-11-26T13:00:00.000Z".to_string()),
+            repeating.occurrences().unwrap().nth(1000).map(|dt| dt.to_string())
+        );
+    }
+
+    #[test]
+    fn start_unknown() {
+        assert!(repeating_interval("R5/P1D/2008-03-01T13:00:00Z").unwrap().occurrences().is_none());
+        assert!(repeating_interval("R5/2008-03-01/P1D").unwrap().occurrences().is_none());
+        assert!(repeating_interval("R5/2015-02-30T10:00Z/P1D").unwrap().occurrences().is_none());
+    }
+
+    #[test]
+    fn starting_on_a_leap_second() {
+        assert_eq!(
+            vec!["2016-12-31T23:59:60.000Z", "2017-01-01T00:00:00.000Z", "2017-01-01T00:00:01.000Z"],
+            occurrences("R3/2016-12-31T23:59:60Z/PT1S")
+        );
+        assert_eq!(
+            vec!["2016-12-31T23:59:60.500Z", "2017-01-01T00:00:00.000Z"],
+            occurrences("R2/2016-12-31T23:59:60.5Z/PT0.5S")
+        );
+        // a leap second is one second before the next day
+        assert_eq!(
+            vec!["2016-12-31T23:59:60.000Z", "2017-01-01T00:00:00.000Z"],
+            occurrences("R2/2016-12-31T23:59:60Z/2017-01-01T00:00:00Z")
+        );
+        assert_eq!(
+            vec!["2015-12-31T24:00:00.000Z", "2016-01-02T00:00:00.000Z"],
+            occurrences("R2/2015-12-31T24:00:00Z/P1D")
+        );
+    }
+}
+
+#[rustfmt::skip]
+mod test_rfc3339 {
+    use super::*;
+    use iso8601::rfc3339::{format_rfc3339, parse_rfc3339};
+
+    #[test]
+    fn rfc_examples() {
+        for s in [
+            "1985-04-12T23:20:50.52Z",
+            "1996-12-19T16:39:57-08:00",
+            "1990-12-31T23:59:60Z",
+            "1990-12-31T15:59:60-08:00",
+            "1937-01-01T12:00:27.87+00:20",
+        ] {
+            assert!(parse_rfc3339(s).is_ok(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn accepts_what_iso8601_parsers_dont() {
+        let expected = datetime("1985-04-12T23:20:50.52Z").unwrap();
+        assert_eq!(Ok(expected), parse_rfc3339("1985-04-12t23:20:50.52z"));
+        assert_eq!(Ok(expected), parse_rfc3339("1985-04-12 23:20:50.52Z"));
+    }
+
+    #[test]
+    fn rejects_what_rfc3339_forbids() {
+        for s in [
+            "1985-102T23:20:50Z",        // ordinal date
+            "1985-W15-5T23:20:50Z",      // week date
+            "19850412T232050Z",          // basic format
+            "1985-04-12T23:20Z",         // missing seconds
+            "1985-04-12T23:20:50",       // missing offset
+            "1985-04-12T23:20:50,52Z",   // comma
+            "1985-04-12T23:20:50.Z",     // empty fraction
+            "1985-04-12T24:00:00Z",      // hour 24
+            "1985-04-12T23:20:50+0100",  // offset without colon
+            "1985-04-12T23:20:50+01",    // offset without minutes
+            "+1985-04-12T23:20:50Z",     // signed year
+            "1985-02-29T23:20:50Z",      // date doesn't exist
+            "1985-04-12T12:00:60Z",      // leap second not at the end of a UTC day
+            "1985-04-12T23:59:60+01:00", // same
+        ] {
+            assert!(parse_rfc3339(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let err = parse_rfc3339("1985-02-29T23:20:50Z").unwrap_err();
+        assert_eq!(ErrorKind::InvalidDate(InvalidDate::LeapDay), err.kind());
+
+        let err = parse_rfc3339("1985-04-12T23:20:50").unwrap_err();
+        assert_eq!((19, Some(Component::Offset), ErrorKind::UnexpectedEnd), (err.position(), err.component(), err.kind()));
+
+        let err = parse_rfc3339("1985-04-12T12:00:60Z").unwrap_err();
+        assert_eq!((17, Some(Component::Second), ErrorKind::OutOfRange), (err.position(), err.component(), err.kind()));
+
+        let err = parse_rfc3339("1985-04-12T23:20:50Z ").unwrap_err();
+        assert_eq!(ErrorKind::TrailingInput, err.kind());
+    }
+
+    #[test]
+    fn format() {
+        let format = |s| format_rfc3339(&datetime(s).unwrap());
+
+        assert_eq!(Some("1985-04-12T23:20:50.520Z".into()), format("1985-04-12T23:20:50.52Z"));
+        assert_eq!(Some("1996-12-19T16:39:57-08:00".into()), format("1996-12-19T16:39:57-08:00"));
+        assert_eq!(Some("2015-06-26T16:43:00+05:30".into()), format("2015-177T16:43+0530"));
+        assert_eq!(Some("2015-06-27T00:00:00Z".into()), format("2015-06-26T24:00:00Z"));
+        assert_eq!(Some("2015-06-26T16:43:00.000001Z".into()), format("2015-06-26T16:43:00.000001Z"));
+
+        assert_eq!(None, format("2015-02-29T16:43:00Z"));
+        assert_eq!(None, format("+12015-06-26T16:43:00Z"));
+        assert_eq!(None, format("-0001-06-26T16:43:00Z"));
+        let mut past_end_of_day = datetime("2015-06-26T23:30:00Z").unwrap();
+        past_end_of_day.time.hour = 24;
+        assert_eq!(None, format_rfc3339(&past_end_of_day));
+    }
+
+    #[test]
+    fn roundtrip() {
+        for s in ["1985-04-12T23:20:50.52Z", "1990-12-31T23:59:60Z", "1937-01-01T12:00:27.87+00:20", "0001-01-01T00:00:00-00:01"] {
+            let datetime = parse_rfc3339(s).unwrap();
+            assert_eq!(Ok(datetime), parse_rfc3339(&format_rfc3339(&datetime).unwrap()));
+        }
+    }
+}
+
+#[rustfmt::skip]
+mod test_lenient_options {
+    use super::*;
+
+    #[test]
+    fn case_insensitive() {
+        let options = ParseOptions::new().case_insensitive(true);
+        let expected = datetime("2015-11-02T10:00:00Z").unwrap();
+
+        assert_eq!(Ok(expected), options.datetime("2015-11-02t10:00:00z"));
+        assert_eq!(date("2015-W45-1"), options.date("2015-w45-1"));
+        assert_eq!(date("2015-W45"), options.date("2015w45"));
+        assert_eq!(time("10:00Z"), options.time("10:00z"));
+        assert_eq!(duration("P1Y2M3DT4H5M6S"), options.duration("p1y2m3dt4h5m6s"));
+        assert_eq!(duration("P2W"), options.duration("p2w"));
+        assert_eq!(duration("P0001-02-03T04:05:06"), options.duration("p0001-02-03t04:05:06"));
+        assert_eq!(interval("2015-11-02T10:00Z/PT1H"), options.interval("2015-11-02t10:00z/pt1h"));
+        assert_eq!(repeating_interval("R2/2015-11-02/P1D"), options.repeating_interval("r2/2015-11-02/p1d"));
+
+        // a space isn't a separator with this option alone
+        assert!(options.datetime("2015-11-02 10:00:00Z").is_err());
+    }
+
+    #[test]
+    fn space_separator() {
+        let options = ParseOptions::new().space_separator(true);
+        let expected = datetime("2015-11-02T10:00:00Z").unwrap();
+
+        assert_eq!(Ok(expected), options.datetime("2015-11-02 10:00:00Z"));
+        assert_eq!(Ok(expected), options.datetime("2015-11-02T10:00:00Z"));
+        assert_eq!(interval("2015-11-02T10:00/03T12:00"), options.interval("2015-11-02 10:00/03 12:00"));
+
+        // only between date and time, not in durations
+        assert!(options.duration("P1D T1H").is_err());
+        assert!(options.datetime("2015-11-02t10:00:00Z").is_err());
+    }
+
+    #[test]
+    fn off_by_default() {
+        for s in ["2015-11-02t10:00:00Z", "2015-11-02T10:00:00z", "2015-11-02 10:00:00Z"] {
+            let err = datetime(s).unwrap_err();
+            assert!(err.kind() == ErrorKind::UnexpectedCharacter || err.kind() == ErrorKind::TrailingInput, "{}", s);
+        }
+        assert_eq!(Some(Component::Separator), datetime("2015-11-02t10:00:00Z").unwrap_err().component());
+        assert_eq!(Some(Component::Designator), duration("p1d").unwrap_err().component());
+    }
+}
+
+#[rustfmt::skip]
+mod test_negative_durations {
+    use super::*;
+    use core::time::Duration as StdDuration;
+
+    #[test]
+    fn parse() {
+        assert_eq!(duration("P-3DT-4H"), duration("-P3DT4H"));
+        assert_eq!(
+            Ok(Duration::YMDHMS { year: 0, month: 0, week: 0, day: 0, hour: 0, minute: 0, second: -1, nanosecond: -500_000_000 }),
+            duration("PT-1.5S")
+        );
+        assert_eq!(Ok(Duration::Weeks(-1)), duration("P-1W"));
+        assert_eq!(
+            Ok(Duration::YMDHMS { year: 0, month: 0, week: 0, day: 0, hour: -2, minute: -30, second: 0, nanosecond: 0 }),
+            duration("-PT2.5H")
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("-P3DT4H", duration("P-3DT-4H").unwrap().to_string());
+        assert_eq!("-PT1.500S", duration("PT-1.5S").unwrap().to_string());
+        assert_eq!("P1DT-1H", duration("P1DT-1H").unwrap().to_string());
+    }
+
+    #[test]
+    fn is_zero() {
+        assert!(duration("-P0D").unwrap().is_zero());
+        assert!(duration("PT-0S").unwrap().is_zero());
+        assert!(!duration("PT-0.1S").unwrap().is_zero());
+    }
+
+    #[test]
+    fn into_std() {
+        assert_eq!(Err(NegativeDuration), StdDuration::try_from(duration("-P1D").unwrap()));
+        assert_eq!(Err(NegativeDuration), StdDuration::try_from(duration("PT-0.5S").unwrap()));
+        assert_eq!(Ok(StdDuration::from_secs(23 * 3600)), StdDuration::try_from(duration("P1DT-1H").unwrap()));
+        assert_eq!(Ok(StdDuration::new(59, 500_000_000)), StdDuration::try_from(duration("PT1M-0.5S").unwrap()));
+    }
+
+    #[test]
+    fn out_of_range() {
+        let err = duration("P-2147483648Y").unwrap_err();
+        assert_eq!((ErrorKind::OutOfRange, Some(Component::Year)), (err.kind(), err.component()));
+        assert_eq!(ErrorKind::OutOfRange, duration("-P2147483648D").unwrap_err().kind());
+    }
+
+    #[test]
+    fn negate() {
+        assert_eq!(duration("-P1Y2DT3H"), Ok(-duration("P1Y2DT3H").unwrap()));
+        assert_eq!(duration("P1DT-2H"), Ok(-duration("-P1DT-2H").unwrap()));
+        assert_eq!(Duration::Weeks(-2), -Duration::Weeks(2));
+        assert_eq!(Some(Duration::Weeks(-2)), Duration::Weeks(2).checked_neg());
+
+        let min = Duration::YMDHMS { year: 0, month: 0, week: 0, day: 0, hour: 0, minute: 0, second: i32::MIN, nanosecond: 0 };
+        assert_eq!(None, min.checked_neg());
+        assert_eq!(None, Duration::Weeks(i32::MIN).checked_neg());
+    }
+
+    #[test]
+    #[should_panic]
+    fn negate_min() {
+        let _ = -Duration::Weeks(i32::MIN);
+    }
+
+    #[test]
+    fn interval() {
+        let interval = super::interval("-P1D/2015-06-26").unwrap();
+        assert_eq!(Interval::DurationEnd { duration: duration("-P1D").unwrap(), end: IntervalEndpoint::Date(date("2015-06-26").unwrap()) }, interval);
+        assert_eq!("-P1D/2015-06-26", interval.to_string());
+    }
+}
+
+#[rustfmt::skip]
+mod test_duration_alternative_format {
+    use super::*;
+
+    fn ymdhms(year: i32, month: i32, day: i32, hour: i32, minute: i32, second: i32, nanosecond: i32) -> Duration {
+        Duration::YMDHMS { year, month, week: 0, day, hour, minute, second, nanosecond }
+    }
+
+    #[test]
+    fn extended() {
+        assert_eq!(Ok(ymdhms(0, 0, 1, 0, 0, 0, 0)), duration("P0000-00-01T00:00:00"));
+        assert_eq!(Ok(ymdhms(1, 2, 3, 4, 5, 6, 500_000_000)), duration("P0001-02-03T04:05:06.5"));
+        assert_eq!(Ok(ymdhms(0, 12, 30, 23, 60, 60, 0)), duration("P0000-12-30T23:60:60"));
+        assert_eq!(Ok(ymdhms(0, 12, 30, 24, 0, 0, 0)), duration("P0000-12-30T24:00:00"));
+        assert_eq!(Ok(ymdhms(2015, 11, 3, 21, 56, 0, 0)), duration("P2015-11-03T21:56"));
+        assert_eq!(Ok(ymdhms(0, 0, 0, 21, 30, 0, 0)), duration("P0000-00-00T21.5"));
+    }
+
+    #[test]
+    fn basic() {
+        assert_eq!(Ok(ymdhms(0, 0, 1, 0, 0, 0, 0)), duration("P00000001T000000"));
+        assert_eq!(Ok(ymdhms(1, 2, 3, 4, 5, 6, 500_000_000)), duration("P00010203T040506,5"));
+        assert_eq!(Ok(ymdhms(1, 2, 3, 4, 5, 0, 0)), duration("P00010203T0405"));
+        assert_eq!(Ok(ymdhms(-1, -2, -3, -4, -5, -6, 0)), duration("-P00010203T040506"));
+    }
+
+    #[test]
+    fn carry_over_points() {
+        for (s, component) in [
+            ("P0000-13-00T00:00:00", Component::Month),
+            ("P0000-00-31T00:00:00", Component::Day),
+            ("P0000-00-00T25:00:00", Component::Hour),
+            ("P0000-00-00T00:61:00", Component::Minute),
+            ("P0000-00-00T00:00:61", Component::Second),
+            ("P0000-00-00T24:01:00", Component::Hour),
+            ("P0000-00-00T24:00:01", Component::Hour),
+            ("P0000-00-00T24:00:00.5", Component::Hour),
+            ("P00000000T24.5", Component::Hour),
+        ] {
+            let err = duration(s).unwrap_err();
+            assert_eq!((ErrorKind::OutOfRange, Some(component)), (err.kind(), err.component()), "{}", s);
+        }
+    }
+
+    #[test]
+    fn whole_days_of_hours() {
+        assert_eq!(Ok(ymdhms(0, 0, 0, 24, 0, 0, 0)), duration("P0000-00-00T24:00:00"));
+        assert_eq!(Ok(ymdhms(0, 0, 0, 24, 0, 0, 0)), duration("P00000000T24"));
+    }
+
+    #[test]
+    fn no_mixed_formats() {
+        assert!(duration("P0001-02-03T040506").is_err());
+        assert!(duration("P00010203T04:05:06").is_err());
+        assert!(duration("P0001-0203T04:05:06").is_err());
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(Some("P0000-00-01T00:00:00".to_string()), duration("P1D").unwrap().format_alternative());
+        assert_eq!(Some("P0001-02-03T04:05:06.500".to_string()), duration("P1Y2M3DT4H5M6.5S").unwrap().format_alternative());
+        assert_eq!(Some("-P0000-00-00T00:00:01.500".to_string()), duration("PT-1.5S").unwrap().format_alternative());
+        assert_eq!(None, duration("P31D").unwrap().format_alternative());
+        assert_eq!(None, duration("P10000Y").unwrap().format_alternative());
+        assert_eq!(None, duration("P1DT-1H").unwrap().format_alternative());
+        assert_eq!(Some("P0000-00-07T00:00:00".to_string()), duration("P1W").unwrap().format_alternative());
+        assert_eq!(Some("P0000-00-16T12:00:00".to_string()), duration("P2W2DT12H").unwrap().format_alternative());
+        assert_eq!(None, duration("P5W").unwrap().format_alternative());
+
+        assert_eq!(None, duration("PT24H1M").unwrap().format_alternative());
+        for s in ["P0000-00-01T00:00:00", "P0001-02-03T04:05:06.500", "-P9999-12-30T23:60:60", "P0000-00-00T24:00:00"] {
+            assert_eq!(Some(s.to_string()), duration(s).unwrap().format_alternative());
+        }
+    }
+}
+
+#[rustfmt::skip]
+mod test_duration_weeks_with_other_units {
+    use super::*;
+    use core::time::Duration as StdDuration;
+
+    fn ywdh(year: i32, week: i32, day: i32, hour: i32) -> Duration {
+        Duration::YMDHMS { year, month: 0, week, day, hour, minute: 0, second: 0, nanosecond: 0 }
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Ok(ywdh(0, 1, 2, 0)), duration("P1W2D"));
+        assert_eq!(Ok(ywdh(0, 2, 0, 12)), duration("P2WT12H"));
+        assert_eq!(Ok(ywdh(1, 2, 3, 4)), duration("P1Y2W3DT4H"));
+        assert_eq!(Ok(ywdh(0, -1, -2, 0)), duration("-P1W2D"));
+        assert_eq!(Ok(Duration::YMDHMS { year: 0, month: 0, week: 1, day: 0, hour: 12, minute: 0, second: 0, nanosecond: 0 }), duration("P1W0.5D"));
+        // weeks alone are still their own variant
+        assert_eq!(Ok(Duration::Weeks(2)), duration("P2W"));
+        // weeks come between months and days
+        assert!(duration("P2D1W").is_err());
+        assert!(duration("P1W2M").is_err());
+    }
+
+    #[test]
+    fn display() {
+        for s in ["P1W2D", "P2WT12H", "P1Y2W3DT4H", "-P1W2D", "P1WT-1H"] {
+            assert_eq!(s, duration(s).unwrap().to_string());
+        }
+    }
+
+    #[test]
+    fn weeks_only_roundtrip() {
+        // zero days keep weeks from being read back as a `Duration::Weeks`
+        for (s, written) in [("P1W0D", "P1W0D"), ("P1WT0H", "P1W0D"), ("-P1W0D", "-P1W0D"), ("P2W", "P2W")] {
+            let parsed = duration(s).unwrap();
+            assert_eq!(written, parsed.to_string());
+            assert_eq!(Ok(parsed), duration(&parsed.to_string()), "{}", s);
+        }
+        assert_eq!(Ok(ywdh(0, 1, 0, 0)), duration("P1W0D"));
+    }
+
+    #[test]
+    fn into_std() {
+        assert_eq!(Ok(StdDuration::from_secs(9 * 86_400)), StdDuration::try_from(duration("P1W2D").unwrap()));
+        assert_eq!(Ok(StdDuration::from_secs(14 * 86_400 + 12 * 3600)), StdDuration::try_from(duration("P2WT12H").unwrap()));
+    }
+
+    #[test]
+    fn repeating_interval() {
+        let repeating = super::repeating_interval("R3/2015-01-01T00:00:00Z/P1W2D").unwrap();
+        let days: Vec<String> = repeating.occurrences().unwrap().map(|occurrence| occurrence.date.to_string()).collect();
+        assert_eq!(days, ["2015-01-01", "2015-01-10", "2015-01-19"]);
+    }
+}
+
+#[rustfmt::skip]
+mod test_offsets {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(Offset::Local, time("16:43:16").unwrap().offset);
+        assert_eq!(Offset::Utc, time("16:43:16Z").unwrap().offset);
+        assert_eq!(Offset::Fixed(UtcOffset::UTC), time("16:43:16+00:00").unwrap().offset);
+        assert_eq!(Offset::Fixed(UtcOffset::UTC), time("16:43:16+00").unwrap().offset);
+        assert_eq!(Offset::Fixed(UtcOffset::from_hms(-5, -30, 0).unwrap()), time("16:43:16-05:30").unwrap().offset);
+        assert_eq!(Offset::Unknown, time("16:43:16-00:00").unwrap().offset);
+        assert_eq!(Offset::Unknown, time("164316-0000").unwrap().offset);
+    }
+
+    #[test]
+    fn display() {
+        for s in ["16:43:16.000", "16:43:16.000Z", "16:43:16.000+00:00", "16:43:16.000-05:30", "16:43:16.000-00:00"] {
+            assert_eq!(s, time(s).unwrap().to_string());
+        }
+    }
+
+    #[test]
+    fn utc_offset_seconds() {
+        assert_eq!(None, Offset::Local.utc_offset_seconds());
+        assert_eq!(Some(0), Offset::Utc.utc_offset_seconds());
+        assert_eq!(Some(0), Offset::Unknown.utc_offset_seconds());
+        assert_eq!(Some(-19_800), Offset::Fixed(UtcOffset::from_hms(-5, -30, 0).unwrap()).utc_offset_seconds());
+    }
+
+    #[test]
+    fn rfc3339() {
+        use iso8601::rfc3339::format_rfc3339;
+
+        assert_eq!(None, format_rfc3339(&datetime("2015-06-26T16:43:16").unwrap()));
+        assert_eq!(Some("2015-06-26T16:43:16-00:00".to_string()), format_rfc3339(&datetime("2015-06-26T16:43:16-00:00").unwrap()));
+        assert_eq!(Some("2015-06-26T16:43:16+00:00".to_string()), format_rfc3339(&datetime("2015-06-26T16:43:16+00:00").unwrap()));
+    }
+}
+
+#[rustfmt::skip]
+mod test_utc_offset {
+    use super::*;
+
+    #[test]
+    fn constructors() {
+        assert_eq!(Some(-19_800), UtcOffset::from_hms(-5, -30, 0).map(|o| o.total_seconds()));
+        assert_eq!(Some(1_050), UtcOffset::from_hms(0, 17, 30).map(|o| o.total_seconds()));
+        assert_eq!(UtcOffset::from_seconds(-19_800), UtcOffset::from_hms(-5, -30, 0));
+        assert_eq!(Some(UtcOffset::UTC), UtcOffset::from_hms(0, 0, 0));
+        assert!(UtcOffset::from_hms(18, 0, 0).is_some());
+        assert!(UtcOffset::from_seconds(UtcOffset::MAX_SECONDS + 1).is_none());
+        assert!(UtcOffset::from_seconds(-UtcOffset::MAX_SECONDS - 1).is_none());
+        // all components have the sign of the offset
+        assert_eq!(None, UtcOffset::from_hms(-5, 30, 0));
+        assert_eq!(None, UtcOffset::from_hms(18, 0, 1));
+        assert_eq!(None, UtcOffset::from_hms(24, 59, 0));
+        assert_eq!(None, UtcOffset::from_hms(5, 60, 0));
+        assert_eq!(None, UtcOffset::from_hms(0, 0, -60));
+    }
+
+    #[test]
+    fn components() {
+        let offset = UtcOffset::from_hms(-9, -30, -15).unwrap();
+        assert_eq!((-9, -30, -15), (offset.hours(), offset.minutes(), offset.seconds()));
+        assert_eq!(-34_215, offset.total_seconds());
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("+00:00", UtcOffset::UTC.to_string());
+        assert_eq!("+05:30", UtcOffset::from_hms(5, 30, 0).unwrap().to_string());
+        assert_eq!("-00:30", UtcOffset::from_hms(0, -30, 0).unwrap().to_string());
+        assert_eq!("+00:17:30", UtcOffset::from_hms(0, 17, 30).unwrap().to_string());
+        assert_eq!("-18:00", UtcOffset::from_hms(-18, 0, 0).unwrap().to_string());
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Ok(UtcOffset::from_hms(-5, -30, 0).unwrap()), "-05:30".parse());
+        assert_eq!(Ok(UtcOffset::UTC), "Z".parse());
+
+        let err = "+24:00".parse::<UtcOffset>().unwrap_err();
+        assert_eq!((0, Some(Component::Offset), ErrorKind::OutOfRange), (err.position(), err.component(), err.kind()));
+        assert_eq!(ErrorKind::TrailingInput, "+05:30x".parse::<UtcOffset>().unwrap_err().kind());
+    }
+
+    #[test]
+    fn in_time() {
+        assert_eq!(Offset::Fixed(UtcOffset::from_hms(0, 17, 30).unwrap()), time("12:00:00+00:17:30").unwrap().offset);
+        assert_eq!(Offset::Fixed(UtcOffset::from_hms(0, -17, -30).unwrap()), time("120000-001730").unwrap().offset);
+        assert_eq!("12:00:00.000+00:17:30", time("12:00:00+00:17:30").unwrap().to_string());
+
+        let err = time("12:00:00+24:00").unwrap_err();
+        assert_eq!((8, Some(Component::Offset), ErrorKind::OutOfRange), (err.position(), err.component(), err.kind()));
+        assert!(datetime("2015-06-26T16:43:16-19:00").is_err());
+    }
+
+    #[test]
+    fn rfc3339() {
+        use iso8601::rfc3339::{format_rfc3339, parse_rfc3339};
+
+        assert!(parse_rfc3339("2015-06-26T16:43:16+19:00").is_err());
+        assert_eq!(None, format_rfc3339(&datetime("2015-06-26T16:43:16+00:17:30").unwrap()));
+    }
+
+    #[test]
+    fn set_tz() {
+        let time = time("12:00:00").unwrap().set_tz(UtcOffset::from_hms(-3, -30, 0).unwrap());
+        assert_eq!(Some(-12_600), time.offset.utc_offset_seconds());
+    }
+}
+
+#[rustfmt::skip]
+mod test_edtf {
+    use super::*;
+    use iso8601::edtf::{self, parse_edtf, Edtf, Endpoint, Qualifier, Season, Unspecified};
+
+    fn edtf_date(s: &str) -> edtf::Date {
+        match parse_edtf(s) {
+            Ok(Edtf::Date(date)) => date,
+            other => panic!("{} is no date: {:?}", s, other),
+        }
+    }
+
+    fn ymd(year: i32, month: Option<u32>, day: Option<u32>) -> edtf::Date {
+        edtf::Date { year, month, day, ..edtf::Date::default() }
+    }
+
+    #[test]
+    fn level_0() {
+        assert_eq!(ymd(1985, Some(4), Some(12)), edtf_date("1985-04-12"));
+        assert_eq!(ymd(1985, Some(4), None), edtf_date("1985-04"));
+        assert_eq!(ymd(1985, None, None), edtf_date("1985"));
+        assert_eq!(ymd(0, None, None), edtf_date("0000"));
+        assert_eq!(
+            Ok(Edtf::DateTime(DateTime {
+                date: Date::YMD { year: 1985, month: 4, day: 12 },
+                time: Time { hour: 23, minute: 20, second: 30, nanosecond: 0, offset: Offset::Utc, precision: TimePrecision::Second },
+            })),
+            parse_edtf("1985-04-12T23:20:30Z")
+        );
+        assert!(matches!(parse_edtf("1985-04-12T23:20:30"), Ok(Edtf::DateTime(dt)) if dt.time.offset == Offset::Local));
+        assert!(matches!(parse_edtf("1985-04-12T23:20:30-04"), Ok(Edtf::DateTime(dt)) if dt.time.offset.utc_offset_seconds() == Some(-4 * 3600)));
+        assert!(matches!(parse_edtf("1985-04-12T23:20:30+04:30"), Ok(Edtf::DateTime(dt)) if dt.time.offset.utc_offset_seconds() == Some(16_200)));
+        assert_eq!(
+            Ok(Edtf::Interval(edtf::Interval {
+                start: Endpoint::Date(ymd(2004, Some(2), Some(1))),
+                end: Endpoint::Date(ymd(2005, Some(2), None)),
+            })),
+            parse_edtf("2004-02-01/2005-02")
+        );
+    }
+
+    #[test]
+    fn letter_prefixed_years() {
+        let prefixed = |year| edtf::Date { letter_prefix: true, ..ymd(year, None, None) };
+        assert_eq!(prefixed(170_000_002), edtf_date("Y170000002"));
+        assert_eq!(prefixed(-170_000_002), edtf_date("Y-170000002"));
+        assert_eq!(prefixed(123), edtf_date("Y00123"));
+        assert_eq!(edtf::Date { negative_zero: true, ..prefixed(0) }, edtf_date("Y-00000"));
+        assert_eq!(edtf::Date { negative_zero: true, ..ymd(0, None, None) }, edtf_date("-0000"));
+        // only for years of more than four digits
+        assert!(parse_edtf("Y2004").is_err());
+        assert!(parse_edtf("Y170000002-01").is_err());
+    }
+
+    #[test]
+    fn seasons() {
+        assert_eq!(Some(Season::Spring), edtf_date("2001-21").season());
+        assert_eq!(Some(Season::Summer), edtf_date("2001-22").season());
+        assert_eq!(Some(Season::Autumn), edtf_date("2001-23").season());
+        assert_eq!(Some(Season::Winter), edtf_date("2001-24").season());
+        assert_eq!(None, edtf_date("2001-01").season());
+        assert!(parse_edtf("2001-25").is_err());
+        assert!(parse_edtf("2001-21-01").is_err());
+    }
+
+    #[test]
+    fn qualifiers() {
+        assert_eq!(Some(Qualifier::Uncertain), edtf_date("1984?").qualifier);
+        assert_eq!(Some(Qualifier::Approximate), edtf_date("2004-06~").qualifier);
+        assert_eq!(Some(Qualifier::UncertainApproximate), edtf_date("2004-06-11%").qualifier);
+        assert_eq!(None, edtf_date("2004-06-11").qualifier);
+        assert!(parse_edtf("2004-06-11?~").is_err());
+        assert!(parse_edtf("1985-04-12T23:20:30Z?").is_err());
+    }
+
+    #[test]
+    fn unspecified_digits() {
+        let date = edtf_date("201X");
+        assert_eq!((2010, Unspecified { year: 0b1, month: 0, day: 0 }), (date.year, date.unspecified));
+        assert_eq!(Unspecified { year: 0b11, month: 0, day: 0 }, edtf_date("20XX").unspecified);
+        assert_eq!(Unspecified { year: 0, month: 0b11, day: 0 }, edtf_date("2004-XX").unspecified);
+        assert_eq!(Unspecified { year: 0, month: 0, day: 0b11 }, edtf_date("1985-04-XX").unspecified);
+        assert_eq!(Unspecified { year: 0, month: 0b11, day: 0b11 }, edtf_date("1985-XX-XX").unspecified);
+        assert_eq!(Some(Qualifier::Uncertain), edtf_date("201X?").qualifier);
+
+        // only the rightmost digits
+        for s in ["1985-XX-12", "20X4", "201X-04", "2004-1X", "1985-04-1X", "X985"] {
+            assert!(parse_edtf(s).is_err(), "{}", s);
+        }
+        assert!(parse_edtf("1985-04-XXT10:00:00").is_err());
+    }
+
+    #[test]
+    fn intervals() {
+        let interval = |s| match parse_edtf(s) {
+            Ok(Edtf::Interval(interval)) => (interval.start, interval.end),
+            other => panic!("{} is no interval: {:?}", s, other),
+        };
+        let date = |s| Endpoint::Date(edtf_date(s));
+
+        assert_eq!((date("1985-04-12"), Endpoint::Open), interval("1985-04-12/.."));
+        assert_eq!((Endpoint::Open, date("1985-04-12")), interval("../1985-04-12"));
+        assert_eq!((date("1985-04-12"), Endpoint::Unknown), interval("1985-04-12/"));
+        assert_eq!((Endpoint::Unknown, date("1985-04-12")), interval("/1985-04-12"));
+        assert_eq!((date("1984~"), date("2004-06")), interval("1984~/2004-06"));
+        assert_eq!((date("1984-06-02?"), date("2004-08-08~")), interval("1984-06-02?/2004-08-08~"));
+        assert_eq!((date("1984?"), date("2004%")), interval("1984?/2004%"));
+        assert_eq!((date("2001-21"), date("201X")), interval("2001-21/201X"));
+
+        for s in ["../..", "/", "../", "/..", "1985/2004/2010"] {
+            assert!(parse_edtf(s).is_err(), "{}", s);
+        }
+        // datetimes are no endpoints
+        assert!(parse_edtf("1985-04-12T23:20:30Z/..").is_err());
+    }
+
+    #[test]
+    fn errors() {
+        let err = parse_edtf("2004-13").unwrap_err();
+        assert_eq!((5, Some(Component::Month), ErrorKind::OutOfRange), (err.position(), err.component(), err.kind()));
+        let err = parse_edtf("1985/2004-02-30").unwrap_err();
+        assert_eq!(ErrorKind::InvalidDate(InvalidDate::DayOfMonth), err.kind());
+        let err = parse_edtf("1985-04-12T24:30:00").unwrap_err();
+        assert_eq!((11, Some(Component::Hour), ErrorKind::OutOfRange), (err.position(), err.component(), err.kind()));
+        // EDTF has no end of the day
+        let err = parse_edtf("2004-01-01T24:00:00").unwrap_err();
+        assert_eq!((11, Some(Component::Hour), ErrorKind::OutOfRange), (err.position(), err.component(), err.kind()));
+        let err = parse_edtf("2004-06-32").unwrap_err();
+        assert_eq!((8, Some(Component::Day), ErrorKind::OutOfRange), (err.position(), err.component(), err.kind()));
+        for s in ["", "85", "1985-4", "1985-04-12 23:20:30", "1985-04-12T23:20", "2015W01", "+1985", "1985-04-12T23:20:30.5Z"] {
+            assert!(parse_edtf(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn display() {
+        for s in [
+            "1985-04-12", "1985-04", "1985", "-1985", "0000", "-0000", "Y170000002", "Y-170000002", "Y00123", "Y-00000", "2001-21",
+            "1984?", "2004-06~", "2004-06-11%", "201X", "20XX", "2004-XX", "1985-04-XX", "1985-XX-XX", "-10XX~",
+            "1985-04-12T23:20:30", "1985-04-12T23:20:30Z", "1985-04-12T23:20:30-04:00", "1985-04-12T23:20:30+04:30",
+            "1964/2008", "1985-04-12/..", "../1985-04-12", "1985-04-12/", "/1985-04-12", "1984-06-02?/2004-08-08~",
+        ] {
+            assert_eq!(s, parse_edtf(s).unwrap().to_string());
+            assert_eq!(Ok(parse_edtf(s).unwrap()), s.parse::<Edtf>());
+        }
+    }
+}
+
+#[rustfmt::skip]
+mod test_edtf_level_2 {
+    use iso8601::edtf::{self, parse_edtf, ComponentQualifiers, Edtf, Qualifier, Qualifiers, SetMember};
+
+    fn edtf_date(s: &str) -> edtf::Date {
+        match parse_edtf(s) {
+            Ok(Edtf::Date(date)) => date,
+            other => panic!("{} is no date: {:?}", s, other),
+        }
+    }
+
+    fn qualifiers(s: &str) -> (Option<Qualifier>, Option<Qualifier>, Option<Qualifier>) {
+        let date = edtf_date(s);
+        (date.year_qualifier(), date.month_qualifier(), date.day_qualifier())
+    }
+
+    #[test]
+    fn component_qualifiers() {
+        use Qualifier::*;
+
+        assert_eq!((None, Some(Uncertain), None), qualifiers("2004-?06-11"));
+        assert_eq!((Some(Approximate), Some(Approximate), None), qualifiers("2004-06~-11"));
+        assert_eq!((Some(Uncertain), None, Some(Approximate)), qualifiers("?2004-06-~11"));
+        assert_eq!((Some(UncertainApproximate), Some(Uncertain), Some(Uncertain)), qualifiers("2004~-06-11?"));
+        assert_eq!((Some(Uncertain), Some(Uncertain), None), qualifiers("2004-06?"));
+        assert_eq!((Some(Approximate), None, None), qualifiers("~2004"));
+
+        let date = edtf_date("2004-?06~-11");
+        assert_eq!(
+            ComponentQualifiers {
+                month: Qualifiers { before: Some(Uncertain), after: Some(Approximate) },
+                ..ComponentQualifiers::default()
+            },
+            date.qualifiers
+        );
+        assert_eq!(None, date.qualifier);
+        // the qualifier after the last component is the one of the whole date
+        assert_eq!((Some(Uncertain), ComponentQualifiers::default()), (edtf_date("2004-06-11?").qualifier, edtf_date("2004-06-11?").qualifiers));
+
+        for s in ["2004-??06", "2004-06-11??", "2004?-06?-11?-", "?2004-06-11T10:00:00"] {
+            assert!(parse_edtf(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn exponential_years() {
+        let date = edtf_date("Y-17E7");
+        assert_eq!((-170_000_000, Some(7), None), (date.year, date.exponent, date.significant_digits));
+        assert_eq!((170_000, Some(4)), (edtf_date("Y17E4").year, edtf_date("Y17E4").exponent));
+        let date = edtf_date("Y-17E7S3");
+        assert_eq!((-170_000_000, Some(7), Some(3)), (date.year, date.exponent, date.significant_digits));
+        let date = edtf_date("1950S2");
+        assert_eq!((1950, None, Some(2)), (date.year, date.exponent, date.significant_digits));
+        assert_eq!(Some(3), edtf_date("Y171010000S3").significant_digits);
+        assert_eq!(Some(Qualifier::Approximate), edtf_date("1950S2~").qualifier);
+
+        assert_eq!(iso8601::ErrorKind::OutOfRange, parse_edtf("Y3E9").unwrap_err().kind());
+        assert_eq!((0, Some(12)), (edtf_date("Y0E12").year, edtf_date("Y0E12").exponent));
+        for s in ["Y17E", "YE7", "17E7", "1950S", "1950S0", "1950S2-01", "Y17E7-01"] {
+            assert!(parse_edtf(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn sets() {
+        let date = |s| SetMember::Date(edtf_date(s));
+
+        assert_eq!(
+            Ok(Edtf::OneOf(vec![
+                date("1667"),
+                date("1668"),
+                SetMember::Range { start: edtf_date("1670"), end: edtf_date("1672") },
+            ])),
+            parse_edtf("[1667,1668,1670..1672]")
+        );
+        assert_eq!(Ok(Edtf::AllOf(vec![date("1960"), date("1961-12")])), parse_edtf("{1960,1961-12}"));
+        assert_eq!(Ok(Edtf::OneOf(vec![SetMember::Earlier(edtf_date("1760-12-03"))])), parse_edtf("[..1760-12-03]"));
+        assert_eq!(
+            Ok(Edtf::OneOf(vec![date("1760-01"), SetMember::Later(edtf_date("1760-12"))])),
+            parse_edtf("[1760-01,1760-12..]")
+        );
+        assert_eq!(Ok(Edtf::AllOf(vec![date("2004-?06-11"), date("Y-17E7")])), parse_edtf("{2004-?06-11,Y-17E7}"));
+
+        for s in ["[]", "{}", "[1667", "[1667}", "{1667]", "[1667,]", "[1667..]..", "[1760..,1761]", "[1760,..1761]", "[1667 ,1668]", "[1985-04-12T23:20:30Z]", "[1667]/1668"] {
+            assert!(parse_edtf(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn display() {
+        for s in [
+            "2004-?06-11", "2004-06~-11", "?2004-06-~11", "%2004-?06~-XX", "2004~-06-11?",
+            "Y-17E7", "Y17E4", "Y-17E7S3", "1950S2", "Y171010000S3", "1950S2~",
+            "[1667,1668,1670..1672]", "{1960,1961-12}", "[..1760-12-03]", "[1760-01,1760-12..]", "{2004-?06-11,Y-17E7}",
+            "2004-?06/2005~-01", "Y0E12", "Y-0E3",
+        ] {
+            assert_eq!(s, parse_edtf(s).unwrap().to_string());
+        }
+    }
+
+    #[test]
+    fn display_large_exponents() {
+        // the exponent is public, a power of ten beyond an `i32` leaves a year of zero
+        for exponent in [9, 10, 31, u32::MAX] {
+            let date = edtf::Date { year: 0, exponent: Some(exponent), ..edtf::Date::default() };
+            assert_eq!(format!("Y0E{}", exponent), date.to_string());
+        }
+        let date = edtf::Date { year: 2_000_000_000, exponent: Some(9), ..edtf::Date::default() };
+        assert_eq!("Y2E9", date.to_string());
+    }
+}
+
+#[rustfmt::skip]
+mod test_truncated_dates {
+    use super::*;
+    use iso8601::{truncated_date, TruncatedDate};
+
+    #[test]
+    fn parse() {
+        assert_eq!(Ok(TruncatedDate::YearOfCentury { yy: 15, month: 11, day: 2 }), truncated_date("151102"));
+        assert_eq!(Ok(TruncatedDate::YearOfCentury { yy: 15, month: 11, day: 2 }), truncated_date("15-11-02"));
+        assert_eq!(Ok(TruncatedDate::MonthDay { month: 11, day: 2 }), truncated_date("--11-02"));
+        assert_eq!(Ok(TruncatedDate::MonthDay { month: 11, day: 2 }), truncated_date("--1102"));
+        assert_eq!(Ok(TruncatedDate::Month { month: 11 }), truncated_date("--11"));
+        assert_eq!(Ok(TruncatedDate::Day { day: 2 }), truncated_date("---02"));
+        assert_eq!(Ok(TruncatedDate::WeekDay { ww: 45, d: 1 }), truncated_date("-W45-1"));
+        assert_eq!(Ok(TruncatedDate::WeekDay { ww: 45, d: 1 }), truncated_date("-W451"));
+        assert_eq!(Ok(TruncatedDate::Week { ww: 45 }), truncated_date("-W45"));
+        assert_eq!(Ok(TruncatedDate::DayOfWeek { d: 1 }), truncated_date("-W-1"));
+        assert_eq!(Ok(TruncatedDate::Ordinal { ddd: 306 }), truncated_date("-306"));
+        assert_eq!(Ok(TruncatedDate::Day { day: 2 }), "---02".parse());
+
+        // complete dates are no truncated ones
+        assert!(iso8601::date("--11-02").is_err());
+        for s in ["", "2015-11-02", "15-11", "1511", "--11-", "--1", "---2", "-W", "-W54", "-W-8", "-W45-", "-367", "-30", "--"] {
+            assert!(truncated_date(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let err = truncated_date("--13-02").unwrap_err();
+        assert_eq!((2, Some(Component::Month), ErrorKind::OutOfRange), (err.position(), err.component(), err.kind()));
+        let err = truncated_date("---32").unwrap_err();
+        assert_eq!((3, Some(Component::Day), ErrorKind::OutOfRange), (err.position(), err.component(), err.kind()));
+        let err = truncated_date("15-11-02T10:00").unwrap_err();
+        assert_eq!((8, ErrorKind::TrailingInput), (err.position(), err.kind()));
+    }
+
+    #[test]
+    fn complete() {
+        let reference = Date::YMD { year: 2015, month: 6, day: 26 };
+        let complete = |s| truncated_date(s).unwrap().complete(&reference);
+
+        assert_eq!(Some(Date::YMD { year: 2049, month: 11, day: 2 }), complete("49-11-02"));
+        assert_eq!(Some(Date::YMD { year: 2015, month: 11, day: 2 }), complete("--11-02"));
+        assert_eq!(Some(Date::YearMonth { year: 2015, month: 11 }), complete("--11"));
+        assert_eq!(Some(Date::YMD { year: 2015, month: 6, day: 2 }), complete("---02"));
+        assert_eq!(Some(Date::Week { year: 2015, ww: 45, d: 1 }), complete("-W45-1"));
+        assert_eq!(Some(Date::YearWeek { year: 2015, ww: 45 }), complete("-W45"));
+        assert_eq!(Some(Date::Week { year: 2015, ww: 26, d: 1 }), complete("-W-1"));
+        assert_eq!(Some(Date::Ordinal { year: 2015, ddd: 306 }), complete("-306"));
+
+        // the week-numbering year of the reference
+        let new_year = Date::YMD { year: 2021, month: 1, day: 3 };
+        assert_eq!(Some(Date::Week { year: 2020, ww: 53, d: 1 }), truncated_date("-W-1").unwrap().complete(&new_year));
+        // any complete representation of the reference
+        let ordinal = Date::Ordinal { year: 1999, ddd: 365 };
+        assert_eq!(Some(Date::YMD { year: 1902, month: 1, day: 1 }), truncated_date("020101").unwrap().complete(&ordinal));
+
+        // the completed date doesn't exist
+        for s in ["---31", "--02-29", "15-02-29", "-366"] {
+            assert_eq!(None, complete(s), "{}", s);
+        }
+        assert_eq!(None, truncated_date("-W53").unwrap().complete(&Date::YMD { year: 2016, month: 1, day: 4 }));
+        assert_eq!(Some(Date::YMD { year: 2016, month: 2, day: 29 }), truncated_date("--02-29").unwrap().complete(&Date::YMD { year: 2016, month: 1, day: 1 }));
+        // the reference has to be complete and exist
+        assert_eq!(None, truncated_date("--11-02").unwrap().complete(&Date::Year { year: 2015 }));
+        assert_eq!(None, truncated_date("--11-02").unwrap().complete(&Date::YMD { year: 2015, month: 2, day: 29 }));
+    }
+
+    #[test]
+    fn complete_at_range_edges() {
+        let max = Date::YMD { year: i32::MAX, month: 1, day: 1 };
+        assert_eq!(Some(Date::YMD { year: i32::MAX, month: 1, day: 1 }), truncated_date("47-01-01").unwrap().complete(&max));
+        assert_eq!(Some(Date::YMD { year: 2147483600, month: 1, day: 1 }), truncated_date("00-01-01").unwrap().complete(&max));
+        assert_eq!(None, truncated_date("48-01-01").unwrap().complete(&max));
+        assert_eq!(None, truncated_date("99-01-01").unwrap().complete(&max));
+
+        let min = Date::YMD { year: i32::MIN, month: 1, day: 1 };
+        assert_eq!(Some(Date::YMD { year: i32::MIN, month: 1, day: 1 }), truncated_date("52-01-01").unwrap().complete(&min));
+        assert_eq!(None, truncated_date("51-01-01").unwrap().complete(&min));
+        assert_eq!(None, truncated_date("00-01-01").unwrap().complete(&min));
+    }
+
+    #[test]
+    fn complete_with_pivot() {
+        let date = truncated_date("49-11-02").unwrap();
+        assert_eq!(Some(Date::YMD { year: 2049, month: 11, day: 2 }), date.complete_with_pivot(1950));
+        assert_eq!(Some(Date::YMD { year: 1949, month: 11, day: 2 }), date.complete_with_pivot(1949));
+        assert_eq!(Some(Date::YMD { year: 2149, month: 11, day: 2 }), date.complete_with_pivot(2050));
+        assert_eq!(Some(Date::YMD { year: -51, month: 11, day: 2 }), date.complete_with_pivot(-70));
+        assert_eq!(Some(Date::YMD { year: 1950, month: 1, day: 1 }), truncated_date("500101").unwrap().complete_with_pivot(1950));
+
+        assert_eq!(None, truncated_date("00-02-29").unwrap().complete_with_pivot(1801));
+        assert_eq!(Some(Date::YMD { year: 2000, month: 2, day: 29 }), truncated_date("00-02-29").unwrap().complete_with_pivot(1950));
+        assert_eq!(None, truncated_date("--11-02").unwrap().complete_with_pivot(1950));
+        assert_eq!(None, date.complete_with_pivot(i32::MAX));
+    }
+
+    #[test]
+    fn display() {
+        for s in ["15-11-02", "--11-02", "--11", "---02", "-W45-1", "-W45", "-W-1", "-306"] {
+            assert_eq!(s, truncated_date(s).unwrap().to_string());
+        }
+        assert_eq!("15-11-02", truncated_date("151102").unwrap().to_string());
+    }
+}
+
+#[rustfmt::skip]
+mod test_reduced_precision_times {
+    use super::*;
+
+    fn hms(time: Time) -> (u32, u32, u32, u32, TimePrecision) {
+        (time.hour, time.minute, time.second, time.nanosecond, time.precision)
+    }
+
+    #[test]
+    fn hour_only() {
+        assert_eq!((10, 0, 0, 0, TimePrecision::Hour), hms(time("10").unwrap()));
+        assert_eq!((24, 0, 0, 0, TimePrecision::Hour), hms(time("24").unwrap()));
+        assert_eq!(Offset::Utc, time("10Z").unwrap().offset);
+        assert_eq!(Some(-5 * 3600), time("10-05").unwrap().offset.utc_offset_seconds());
+        assert_eq!(Some(19_800), time("10+05:30").unwrap().offset.utc_offset_seconds());
+
+        assert_eq!(ErrorKind::OutOfRange, time("25").unwrap_err().kind());
+        for s in ["1", "10:", "10p", "10Z1"] {
+            assert!(time(s).is_err(), "{}", s);
+        }
+        // datetimes still need minutes
+        assert!(datetime("2015-06-26T10").is_err());
+    }
+
+    #[test]
+    fn t_prefixed() {
+        assert_eq!((10, 0, 0, 0, TimePrecision::Hour), hms(time("T10").unwrap()));
+        assert_eq!((10, 30, 0, 0, TimePrecision::Minute), hms(time("T1030").unwrap()));
+        assert_eq!((10, 30, 0, 0, TimePrecision::Minute), hms(time("T10:30").unwrap()));
+        assert_eq!((10, 30, 0, 0, TimePrecision::Second), hms(time("T10:30:00").unwrap()));
+        assert_eq!((10, 30, 15, 500_000_000, TimePrecision::Second), hms(time("T103015.5Z").unwrap()));
+        assert_eq!("T10:30".parse::<Time>(), time("10:30"));
+
+        assert!(time("t10").is_err());
+        assert_eq!(time("T10"), ParseOptions::new().case_insensitive(true).time("t10"));
+        for s in ["T", "TT10", "T 10", "2015-06-26T10:30"] {
+            assert!(time(s).is_err(), "{}", s);
+        }
+        assert!(datetime("2015-06-26TT10:30").is_err());
+    }
+
+    #[test]
+    fn precision() {
+        assert_eq!(TimePrecision::Minute, time("10:30Z").unwrap().precision);
+        assert_eq!(TimePrecision::Second, time("10:30:00").unwrap().precision);
+        assert_eq!(TimePrecision::Second, time("10:30:00.000").unwrap().precision);
+        // a fraction is spread over the lower components
+        assert_eq!((10, 30, 0, 0, TimePrecision::Second), hms(time("10.5").unwrap()));
+        assert_eq!((10, 30, 30, 0, TimePrecision::Second), hms(time("10:30.5").unwrap()));
+        assert_eq!(TimePrecision::Minute, datetime("2015-06-26T10:30").unwrap().time.precision);
+        assert_eq!(TimePrecision::Second, Time::default().precision);
+    }
+
+    #[test]
+    fn equality_ignores_precision() {
+        assert_eq!(time("10"), time("10:00:00"));
+        assert_eq!(time("10:30Z"), time("10:30:00.000Z"));
+        assert_eq!(datetime("2015-06-26T10:00Z"), datetime("2015-06-26T10:00:00Z"));
+        assert_ne!(time("10:30"), time("10:30:01"));
+        assert_ne!(time("10:30"), time("10:30Z"));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("10", time("10").unwrap().to_string());
+        assert_eq!("10Z", time("T10Z").unwrap().to_string());
+        assert_eq!("10:30", time("T1030").unwrap().to_string());
+        assert_eq!("10:30+05:30", time("10:30+05:30").unwrap().to_string());
+        assert_eq!("10:30:00.000", time("T10:30:00").unwrap().to_string());
+        assert_eq!("10:30:00.000", time("10.5").unwrap().to_string());
+        assert_eq!("2015-06-26T10:30Z", datetime("2015-06-26T10:30Z").unwrap().to_string());
+        for s in ["10", "10-05:00", "10:30", "10:30Z", "10:30:15.250"] {
+            assert_eq!(time(s), time(&time(s).unwrap().to_string()));
+        }
+
+        // the components needed are always displayed
+        let time = |hour, minute, second, precision| Time { hour, minute, second, precision, ..Time::default() }.to_string();
+        assert_eq!("10:30", time(10, 30, 0, TimePrecision::Hour));
+        assert_eq!("10:30:15.000", time(10, 30, 15, TimePrecision::Hour));
+        assert_eq!("10:00:15.000", time(10, 0, 15, TimePrecision::Minute));
+        assert_eq!("10:00:00.000", time(10, 0, 0, TimePrecision::Second));
+    }
+}
+
+#[rustfmt::skip]
+mod test_consistent_notation {
+    use super::*;
+
+    const STRICT: ParseOptions = ParseOptions::new().consistent_notation(true);
+
+    fn position(result: Result<impl core::fmt::Debug, ParseError>) -> (usize, Option<Component>, ErrorKind) {
+        let err = result.unwrap_err();
+        (err.position(), err.component(), err.kind())
+    }
+
+    fn mixed(position: usize) -> (usize, Option<Component>, ErrorKind) {
+        (position, Some(Component::Separator), ErrorKind::InconsistentNotation)
+    }
+
+    #[test]
+    fn lenient_by_default() {
+        for s in ["2015-1102", "201511-02", "2015-W451", "2015W45-1"] {
+            assert!(date(s).is_ok(), "{}", s);
+        }
+        for s in ["10:3000", "1030:00", "10:30+0100", "1030+01:00"] {
+            assert!(time(s).is_ok(), "{}", s);
+        }
+        assert!(datetime("2015-11-02T1030").is_ok());
+    }
+
+    #[test]
+    fn dates() {
+        assert_eq!(mixed(7), position(STRICT.date("2015-1102")));
+        assert_eq!(mixed(6), position(STRICT.date("201511-02")));
+        assert_eq!(mixed(8), position(STRICT.date("2015-W451")));
+        assert_eq!(mixed(7), position(STRICT.date("2015W45-1")));
+        for s in ["2015-11-02", "20151102", "2015-W45-1", "2015W451", "2015-306", "2015306", "2015-11", "2015-W45", "2015W45"] {
+            assert!(STRICT.date(s).is_ok(), "{}", s);
+        }
+        let expanded = STRICT.expanded_year(1);
+        assert!(expanded.date("+020151102").is_ok());
+        assert_eq!(mixed(9), position(expanded.date("+02015-1102")));
+    }
+
+    #[test]
+    fn times() {
+        assert_eq!(mixed(5), position(STRICT.time("10:3000")));
+        assert_eq!(mixed(4), position(STRICT.time("1030:00")));
+        assert_eq!(mixed(8), position(STRICT.time("10:30+0100")));
+        assert_eq!(mixed(7), position(STRICT.time("1030+01:00")));
+        assert_eq!(mixed(12), position(STRICT.time("T10:30:00-0500")));
+        for s in ["10:30:00", "103000", "10:30", "1030", "10:30+01:00", "1030+0100", "10:30+01", "1030Z", "10+01:00", "10.5+0100", "T103000.5"] {
+            assert!(STRICT.time(s).is_ok(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn datetimes() {
+        assert_eq!(mixed(13), position(STRICT.datetime("2015-11-02T1030")));
+        assert_eq!(mixed(11), position(STRICT.datetime("20151102T10:30")));
+        assert_eq!(mixed(13), position(STRICT.datetime("2015-W45-1T1030")));
+        assert_eq!(mixed(10), position(STRICT.datetime("2015306T10:30")));
+        assert_eq!(mixed(16), position(STRICT.datetime("20151102T10.5+01:00")));
+        assert_eq!(mixed(22), position(STRICT.datetime("2015-11-02T10:30:00+0100")));
+        for s in ["2015-11-02T10:30:00+01:00", "20151102T103000+0100", "2015-W45-1T10:30Z", "2015306T1030", "-2015-11-02T10:30", "2015-11-02T10.5+01:00"] {
+            assert!(STRICT.datetime(s).is_ok(), "{}", s);
+        }
+
+        assert_eq!("basic and extended format mixed in separator at position 13", STRICT.datetime("2015-11-02T1030").unwrap_err().to_string());
+    }
+
+    #[test]
+    fn intervals() {
+        assert!(STRICT.interval("2015-11-02T10:30/2015-11-03T11:30").is_ok());
+        assert_eq!(mixed(30), position(STRICT.interval("2015-11-02T10:30/2015-11-03T1130")));
+        assert_eq!(mixed(7), position(STRICT.interval("2015-1102/P1D")));
+    }
+}
+
+#[rustfmt::skip]
+mod test_format {
+    use super::*;
+    use iso8601::format::{self, Format, Notation};
+
+    #[test]
+    fn dates_round_trip() {
+        for s in ["2015-11-02", "20151102", "2015-306", "2015306", "2015-W45-1", "2015W451", "2015-W45", "2015W45", "2015-11", "2015", "20", "-2015-11-02", "-20151102"] {
+            let (date, format) = format::parse_date(s).unwrap();
+            assert_eq!(date.display_with(format).to_string(), s);
+        }
+    }
+
+    #[test]
+    fn times_round_trip() {
+        for s in ["10", "1030", "10:30", "103000", "10:30:00", "10:30:00.5", "103000,50", "10:30:00.123456789", "10:30:00.000", "10Z", "1030+01", "10:30+01:00", "1030-0530", "10:30:00-00:00", "103000-00", "10:30:00+00:17:30", "103000+001730", "23:59:60Z", "24:00", "T10", "T1030", "T10:30:00,5Z"] {
+            let (time, format) = format::parse_time(s).unwrap();
+            assert_eq!(time.display_with(format).to_string(), s);
+        }
+    }
+
+    #[test]
+    fn datetimes_round_trip() {
+        for s in ["2015-11-02T10:30:00.000+00:00", "20151102T1030Z", "2015306T1030", "2015-W45-1T10:30:00,25+01", "-2015-11-02T10:30:00.5-05:00", "20151102T103000+0000"] {
+            let (datetime, format) = format::parse_datetime(s).unwrap();
+            assert_eq!(datetime.display_with(format).to_string(), s);
+        }
+    }
+
+    #[test]
+    fn descriptors() {
+        let (_, format) = format::parse_datetime("20151102T103000,50+01").unwrap();
+        assert_eq!(format, Format {
+            date: Notation::Basic,
+            time: Notation::Basic,
+            fraction_digits: Some(2),
+            decimal_comma: true,
+            offset: Notation::Extended,
+            offset_precision: TimePrecision::Hour,
+            time_designator: false,
+        });
+        let (_, format) = format::parse_datetime("2015-11-02T10:30:00Z").unwrap();
+        assert_eq!(format, Format { fraction_digits: Some(0), ..Format::default() });
+        let (_, format) = format::parse_time("T10:30").unwrap();
+        assert_eq!(format, Format { time_designator: true, ..Format::default() });
+        let (_, format) = format::parse_time("10:30").unwrap();
+        assert_eq!(format, Format::default());
+        assert!(format::parse_datetime("2015-11-02T").is_err());
+    }
+
+    #[test]
+    fn normalized() {
+        // mixed notation and fractions of hours and minutes
+        fn written(s: &str) -> String {
+            let (time, format) = format::parse_time(s).unwrap();
+            let written = time.display_with(format).to_string();
+            written
+        }
+        assert_eq!(written("10:3000"), "10:30:00");
+        assert_eq!(written("10.5"), "10:30:00.000");
+        assert_eq!(written("1030.5Z"), "103030.000Z");
+        assert_eq!(written("1030,5"), "103030,000");
+    }
+
+    #[test]
+    fn lower_components_are_kept() {
+        // values changed after parsing aren't cut to the format
+        let (time, format) = format::parse_time("1030+01").unwrap();
+        let time = Time { minute: 30, second: 15, nanosecond: 250_000_000, offset: Offset::Fixed(UtcOffset::from_seconds(5400).unwrap()), ..time };
+        assert_eq!(time.display_with(format).to_string(), "103015.250+01:30");
+        let (time, format) = format::parse_time("10:30:00.5").unwrap();
+        assert_eq!(Time { nanosecond: 123_000_000, ..time }.display_with(format).to_string(), "10:30:00.123");
+    }
+
+    #[test]
+    fn default_is_display() {
+        for s in ["2015-11-02T10:30:00.5+01:00", "2015306T1030Z", "2015-W45-1T10:00-00:00", "2015-11-02T10:30:00+00:17:30"] {
+            let datetime = datetime(s).unwrap();
+            assert_eq!(datetime.display_with(Format::default()).to_string(), datetime.to_string());
+        }
+    }
 }